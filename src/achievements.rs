@@ -0,0 +1,73 @@
+use crate::constants::{ACHIEVEMENT_ANIMALS_DESCRIPTION_STR, ACHIEVEMENT_ANIMALS_STR, ACHIEVEMENT_LESSONS_DESCRIPTION_STR, ACHIEVEMENT_LESSONS_STR, ACHIEVEMENT_SECRET_GATE_DESCRIPTION_STR, ACHIEVEMENT_SECRET_GATE_STR, ACHIEVEMENT_TOAST_FRAMES};
+use crate::i18n::tr;
+use crate::Place;
+
+/// Places of the three lessons of the Ottoman district, a lesson is finished once every NPC of
+/// its street has been heard to the end
+pub const OTTOMAN_LESSON_PLACES: [Place; 3] = [Place::OTTOMAN_LEFT, Place::OTTOMAN_TOP, Place::OTTOMAN_RIGHT];
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Achievement {
+    /// Talked to every dog and cat of the campus
+    CampusAnimals,
+    /// Heard every lecture of the three side streets of the Ottoman district
+    OttomanLessons,
+    /// Walked through the secret gate in a game played with the hints turned off
+    SecretGate,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 3] = [Achievement::CampusAnimals, Achievement::OttomanLessons, Achievement::SecretGate];
+
+    /// Stored in the profiles, stays the same whatever the language
+    pub fn id(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    pub fn get_name(&self) -> String {
+        tr(match self {
+            Achievement::CampusAnimals => ACHIEVEMENT_ANIMALS_STR,
+            Achievement::OttomanLessons => ACHIEVEMENT_LESSONS_STR,
+            Achievement::SecretGate => ACHIEVEMENT_SECRET_GATE_STR,
+        })
+    }
+
+    pub fn get_description(&self) -> String {
+        tr(match self {
+            Achievement::CampusAnimals => ACHIEVEMENT_ANIMALS_DESCRIPTION_STR,
+            Achievement::OttomanLessons => ACHIEVEMENT_LESSONS_DESCRIPTION_STR,
+            Achievement::SecretGate => ACHIEVEMENT_SECRET_GATE_DESCRIPTION_STR,
+        })
+    }
+}
+
+/// Achievements unlocked in this session that still have to be announced, one at a time
+#[derive(Default)]
+pub struct AchievementToasts {
+    pub pending: Vec<Achievement>,
+    /// Frames the first pending achievement stays on the screen
+    pub remaining_frames: i32,
+}
+
+impl AchievementToasts {
+    pub fn push(&mut self, achievement: Achievement) {
+        if self.pending.is_empty() {
+            self.remaining_frames = ACHIEVEMENT_TOAST_FRAMES;
+        }
+        self.pending.push(achievement);
+    }
+
+    pub fn shown(&self) -> Option<Achievement> {
+        self.pending.first().copied()
+    }
+
+    /// Called once per drawn frame
+    pub fn advance(&mut self) {
+        if self.pending.is_empty() { return; }
+        self.remaining_frames -= 1;
+        if self.remaining_frames <= 0 {
+            self.pending.remove(0);
+            self.remaining_frames = ACHIEVEMENT_TOAST_FRAMES;
+        }
+    }
+}
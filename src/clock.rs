@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use crate::constants::{CLOCK_START_MINUTES, MINUTES_PER_DAY};
+
+/// In-game time, advanced by the player's moves and by reading dialogue pages
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct GameClock {
+    /// Number of turns the player has spent since the game started
+    pub turns: u64,
+    /// Minutes passed since midnight of the first day
+    pub minutes: u64,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        GameClock { turns: 0, minutes: CLOCK_START_MINUTES }
+    }
+}
+
+impl GameClock {
+    /// Spends a turn which takes the given minutes
+    pub fn advance(&mut self, minutes: u64) {
+        self.turns += 1;
+        self.minutes += minutes;
+    }
+
+    /// Starting from 1
+    pub fn day(&self) -> u64 {
+        self.minutes / MINUTES_PER_DAY + 1
+    }
+
+    pub fn hour(&self) -> u64 {
+        self.minutes % MINUTES_PER_DAY / 60
+    }
+
+    pub fn minute(&self) -> u64 {
+        self.minutes % 60
+    }
+
+    /// Whether the time of day is in [opening_hour, closing_hour)
+    pub fn is_between(&self, opening_hour: u64, closing_hour: u64) -> bool {
+        (opening_hour..closing_hour).contains(&self.hour())
+    }
+
+    pub fn time_of_day(&self) -> String {
+        format!("{:02}:{:02}", self.hour(), self.minute())
+    }
+}
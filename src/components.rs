@@ -5,8 +5,9 @@ use specs_derive::*;
 use rltk::{RGB};
 use serde::{Deserialize, Serialize};
 use crate::items::ItemName;
-use crate::{Place, TileType};
-use crate::npcs::NpcState;
+use crate::Place;
+use crate::npcs::{MovementBehaviour, NpcState};
+use crate::triggers::{TriggerArea, TriggerCondition, TriggerEffect};
 
 
 #[derive(Component, ConvertSaveload, Clone)]
@@ -35,6 +36,7 @@ pub struct Player {}
 
 #[derive(Component, Debug, ConvertSaveload, Clone)]
 pub struct Name {
+    /// A key into the string tables, or the name the player typed in
     pub name: String,
 }
 
@@ -84,6 +86,14 @@ pub struct SerializeMe;
 #[derive(Component, Serialize, Deserialize, Clone)]
 pub struct SerializationHelper {
     pub map: super::map::Map,
+    #[serde(default)]
+    pub generation: super::map::MapGeneration,
+    #[serde(default)]
+    pub flags: super::triggers::Flags,
+    #[serde(default)]
+    pub clock: super::clock::GameClock,
+    #[serde(default)]
+    pub statistics: super::report::GameStatistics,
 }
 
 #[derive(Component, ConvertSaveload, Clone)]
@@ -102,14 +112,30 @@ pub struct Npc {
     pub state: NpcState,
 }
 
+#[derive(Component, ConvertSaveload, Clone)]
+pub struct Movement {
+    pub behaviour: MovementBehaviour,
+}
+
+#[derive(Component, Serialize, Deserialize, Clone)]
+pub struct Befriendable {}
+
+#[derive(Component, ConvertSaveload, Clone)]
+pub struct Companion {
+    /// Player turns left until the companion may react to something again
+    pub reaction_cooldown: i32,
+}
+
 #[derive(Component, ConvertSaveload, Clone)]
 pub struct Objective {
+    /// Keys into the string tables
     pub objectives: Vec<String>,
     pub index: usize,
 }
 
 #[derive(Component, ConvertSaveload, Clone)]
 pub struct Interaction {
+    /// Pages of lines, each line a key into the string tables
     pub dialogues: Vec<Vec<String>>,
     pub dialogue_index: usize,
     pub get_item_indices: Vec<usize>,
@@ -125,10 +151,15 @@ pub struct DormantPosition {
     pub y: i32,
 }
 
+/// Fires its effects when the player is inside the area of its place and every condition holds,
+/// the exit effects run once that stops being the case
 #[derive(Component, ConvertSaveload, Clone)]
-pub struct RevealerInformation {
-    pub x_end_points: (i32, i32),
-    pub y_end_points: (i32, i32),
-    pub revealer_item: ItemName,
-    pub before_reveal: TileType,
+pub struct Trigger {
+    pub area: TriggerArea,
+    pub conditions: Vec<TriggerCondition>,
+    pub effects: Vec<TriggerEffect>,
+    pub exit_effects: Vec<TriggerEffect>,
+    pub repeatable: bool,
+    pub active: bool,
+    pub fired: bool,
 }
\ No newline at end of file
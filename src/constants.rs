@@ -1,111 +1,282 @@
 use rltk::RGB;
 
 // SCREEN
+/// Size of the console at start, the layout follows the window or the terminal after that
 pub const SCREEN_WIDTH: i32 = 80;
 pub const SCREEN_HEIGHT: i32 = 50;
+pub const TILE_PIXELS: i32 = 12;
+/// The window doesn't shrink the console below the map and a few lines of the log
+pub const MIN_SCREEN_WIDTH: i32 = MAP_WIDTH;
+pub const MIN_SCREEN_HEIGHT: i32 = MAP_HEIGHT + 5;
+pub const SIDE_PANEL_MIN_WIDTH: i32 = 26;
+pub const SIDE_PANEL_MAX_WIDTH: i32 = 40;
 // MAP
 pub const MAP_WIDTH: i32 = 50;
 pub const MAP_HEIGHT: i32 = 40;
 pub const MAP_TILES: i32 = MAP_WIDTH * MAP_HEIGHT;
 // MENU
-pub const TITLE_Y: i32 = SCREEN_HEIGHT / 3;
 pub const MENU_DELTA_Y: i32 = 2;
-pub const MENU_ITEM_1_Y: i32 = SCREEN_HEIGHT / 2;
-pub const TITLE_STR: &str = "OYUNA HOS GELDIN";
-pub const NEW_GAME_STR: &str = "YENI OYUN";
-pub const LOAD_GAME_STR: &str = "OYUN YUKLE";
-pub const QUIT_GAME_STR: &str = "OYUNDAN CIK";
-pub const CREDITS_STR: &str = "KATKIDA BULUNANLAR";
+pub const WINDOW_TITLE_STR: &str = "menu.window_title";
+pub const TITLE_STR: &str = "menu.title";
+pub const NEW_GAME_STR: &str = "menu.new_game";
+pub const LOAD_GAME_STR: &str = "menu.load_game";
+pub const QUIT_GAME_STR: &str = "menu.quit_game";
+pub const CREDITS_STR: &str = "menu.credits";
+pub const CONTROLS_STR: &str = "menu.controls";
+pub const ENTER_NAME_STR: &str = "menu.enter_name";
+pub const GAME_WON_STR: &str = "menu.game_won";
+pub const PLAYER_NAME_MAX_LENGTH: usize = 20;
+pub const PROFILE_MENU_STR: &str = "menu.profile";
+pub const ACHIEVEMENTS_STR: &str = "menu.achievements";
+// EXPORTS
+/// Default of the export directory, the settings file can point it elsewhere
+pub const EXPORT_DIRECTORY: &str = "./exports";
+pub const EXPORT_SAVED_STR: &str = "export.saved";
+pub const EXPORT_FAILED_STR: &str = "export.failed";
+pub const STUDY_SHEET_FILE: &str = "study_sheet.md";
+pub const STUDY_SHEET_TITLE_STR: &str = "study_sheet.title";
+pub const STUDY_SHEET_ITEMS_STR: &str = "study_sheet.items";
+pub const STUDY_SHEET_EXPORT_STR: &str = "study_sheet.export";
+// LEARNING RECORDS
+/// Default of the records file, the settings file can point it elsewhere
+pub const LEARNING_RECORDS_PATH: &str = "./learning_records.jsonl";
+pub const LEARNING_RECORD_TIMEOUT_SECONDS: u64 = 2;
+// REPORT
+pub const REPORT_FILE: &str = "report.md";
+pub const REPORT_TITLE_STR: &str = "report.title";
+pub const REPORT_PLAY_TIME_STR: &str = "report.play_time";
+pub const REPORT_TURNS_STR: &str = "report.turns";
+pub const REPORT_PLACES_STR: &str = "report.places";
+pub const REPORT_NPCS_STR: &str = "report.npcs";
+pub const REPORT_LECTURES_STR: &str = "report.lectures";
+pub const REPORT_GRADE_STR: &str = "report.grade";
+pub const REPORT_GRADE_A_STR: &str = "report.grade.a";
+pub const REPORT_GRADE_B_STR: &str = "report.grade.b";
+pub const REPORT_GRADE_C_STR: &str = "report.grade.c";
+pub const REPORT_GRADE_D_STR: &str = "report.grade.d";
+pub const REPORT_GRADE_F_STR: &str = "report.grade.f";
+pub const REPORT_MAIN_MENU_STR: &str = "report.main_menu";
+pub const REPORT_EXPORT_STR: &str = "report.export";
+pub const REPORT_LABEL_X_FROM_CENTER: i32 = 22;
+pub const REPORT_VALUE_X_FROM_CENTER: i32 = 8;
+pub const REPORT_FIRST_DELTA_Y: i32 = 3;
+pub const REPORT_COMMENT_WIDTH: usize = 50;
+// PROFILES
+pub const PROFILES_PATH: &str = "./profiles.json";
+pub const PROFILES_TITLE_STR: &str = "profiles.title";
+pub const PROFILES_NEW_STR: &str = "profiles.new";
+pub const PROFILES_PLAYTHROUGHS_STR: &str = "profiles.playthroughs";
+pub const PROFILES_LECTURES_STR: &str = "profiles.lectures";
+pub const PROFILES_CURRENT_STR: &str = "profiles.current";
+/// Rows between the title of the profile screen and its first entry
+pub const PROFILES_FIRST_DELTA_Y: i32 = 4;
 // CREDITS
-pub const CREDIT_1_Y: i32 = SCREEN_HEIGHT / 3;
-pub const CREDIT_2_Y: i32 = CREDIT_1_Y + 4;
-pub const CREDIT_3_Y: i32 = CREDIT_2_Y + 4;
-pub const CREDITS_THANKS_Y: i32 = CREDIT_3_Y + 10;
-pub const CREDIT_1_STR: &str = "Aysila Cengiz: Fikri ve destegi icin";
-pub const CREDIT_2_STR: &str = "Herbert Wolverson: Rust ile oyun gelistirmeyi ogrettigi icin";
-pub const CREDIT_3_STR: &str = "Kaynaklar: Prof. Dr. Halil Incancik: Osmanli 9./10./11. Cilt";
-pub const CREDITS_THANKS_STR: &str = "TESEKKURLER";
+pub const CREDITS_DELTA_Y: i32 = 4;
+pub const CREDITS_THANKS_DELTA_Y: i32 = 10;
+pub const CREDIT_1_STR: &str = "credits.1";
+pub const CREDIT_2_STR: &str = "credits.2";
+pub const CREDIT_3_STR: &str = "credits.3";
+pub const CREDITS_THANKS_STR: &str = "credits.thanks";
 // CHARS
 pub const PLAYER_CHAR: char = '☻';
 pub const KEY_CHAR: char = '◘';
 pub const PORTAL_CHAR: char = 'Ω';
+/// Drawn for doors that still need a key when markers are on
+pub const LOCKED_DOOR_CHAR: char = '■';
 pub const NPC_HUMAN_CHAR: char = '☺';
 pub const NPC_DOG_CHAR: char = '▼';
 pub const NPC_CAT_CHAR: char = '▲';
+// CHARS TERMINAL FALLBACK
+pub const PLAYER_FALLBACK_CHAR: char = '@';
+pub const KEY_FALLBACK_CHAR: char = 'k';
+pub const PORTAL_FALLBACK_CHAR: char = 'O';
+pub const LOCKED_DOOR_FALLBACK_CHAR: char = '#';
+pub const NPC_HUMAN_FALLBACK_CHAR: char = 'h';
+pub const NPC_DOG_FALLBACK_CHAR: char = 'd';
+pub const NPC_CAT_FALLBACK_CHAR: char = 'c';
+// FONT
+pub const FONT_FILE: &str = "turkish8x8.png";
+pub const FONT_TILE_SIZE: i32 = 8;
+/// The Turkish letters CP437 lacks, with the CP437 symbol whose cell the font redraws as each of them
+pub const TURKISH_FONT_CHARS: [(char, char); 6] = [('ş', '¢'), ('Ş', '£'), ('ğ', '¥'), ('Ğ', '₧'), ('ı', 'ƒ'), ('İ', 'ª')];
+/// What the terminal build shows for them instead, the other Turkish letters are in CP437
+pub const TURKISH_FALLBACK_CHARS: [(char, char); 6] = [('ş', 's'), ('Ş', 'S'), ('ğ', 'g'), ('Ğ', 'G'), ('ı', 'i'), ('İ', 'I')];
+/// Characters CP437 has no cell for at all, swapped in both builds
+pub const TYPOGRAPHIC_FALLBACK_CHARS: [(char, char); 5] = [('’', '\''), ('‘', '\''), ('“', '"'), ('”', '"'), ('Â', 'A')];
+
+// PAUSE MENU
+pub const PAUSE_BOX_WIDTH: i32 = 30;
+pub const PAUSE_BOX_HEIGHT: i32 = 18;
+pub const PAUSE_TITLE_STR: &str = "pause.title";
+pub const PAUSE_RESUME_STR: &str = "pause.resume";
+pub const PAUSE_SAVE_STR: &str = "pause.save";
+pub const PAUSE_LOAD_STR: &str = "pause.load";
+pub const PAUSE_SETTINGS_STR: &str = "pause.settings";
+pub const PAUSE_HELP_STR: &str = "pause.help";
+pub const PAUSE_QUIT_STR: &str = "pause.quit";
+pub const CONFIRM_OVERWRITE_STR: &str = "pause.confirm_overwrite";
+pub const CONFIRM_QUIT_STR: &str = "pause.confirm_quit";
+pub const CONFIRM_HINT_STR: &str = "pause.confirm_hint";
+pub const CONSOLE_GAME_SAVED: &str = "console.game_saved";
+
+// SETTINGS
+pub const SETTINGS_TRANSITION_STR: &str = "settings.transition";
+pub const SETTINGS_ON_STR: &str = "settings.on";
+pub const SETTINGS_OFF_STR: &str = "settings.off";
+pub const SETTINGS_LANGUAGE_STR: &str = "settings.language";
+pub const SETTINGS_COLORS_STR: &str = "settings.colors";
+pub const SETTINGS_HIGH_CONTRAST_STR: &str = "settings.high_contrast";
+pub const SETTINGS_MARKERS_STR: &str = "settings.markers";
+pub const SETTINGS_TILE_SIZE_STR: &str = "settings.tile_size";
+pub const SETTINGS_HINTS_STR: &str = "settings.hints";
+pub const SETTINGS_LEARNING_RECORDS_STR: &str = "settings.learning_records";
+pub const COLOR_SCHEME_STANDARD_STR: &str = "settings.colors.standard";
+pub const COLOR_SCHEME_PROTANOPIA_STR: &str = "settings.colors.protanopia";
+pub const COLOR_SCHEME_DEUTERANOPIA_STR: &str = "settings.colors.deuteranopia";
+pub const COLOR_SCHEME_TRITANOPIA_STR: &str = "settings.colors.tritanopia";
+pub const SETTINGS_PATH: &str = "./settings.json";
+/// Letter sizes in pixels the settings screen cycles through
+pub const TILE_PIXEL_SIZES: [i32; 6] = [10, 12, 14, 16, 20, 24];
+/// How much backgrounds are darkened in the high contrast mode
+pub const HIGH_CONTRAST_BACKGROUND_SCALE: f32 = 0.5;
+/// Put around the selected menu entry when markers are on
+pub const MENU_MARKER_LEFT: &str = "> ";
+pub const MENU_MARKER_RIGHT: &str = " <";
+
+// HELP
+/// The keys are written this far right of the action names
+pub const HELP_KEYS_DELTA_X: i32 = 20;
+pub const HELP_DELTA_Y: i32 = 1;
+/// Kept free on both sides when the screen is narrower than the help text
+pub const HELP_MARGIN: i32 = 2;
+pub const HELP_EXIT_FROM_BOTTOM: i32 = 4;
+pub const HELP_EXIT_STR: &str = "help.exit";
+pub const HELP_CONTROLS_STR: &str = "help.controls";
+pub const HELP_MECHANICS_STR: &str = "help.mechanics";
+pub const HELP_MECHANICS_WIDTH: usize = 60;
+pub const HELP_MECHANICS: &[&str] = &[
+    "help.mechanics.0",
+    "help.mechanics.1",
+    "help.mechanics.2",
+    "help.mechanics.3",
+    "help.mechanics.4",
+    "help.mechanics.5",
+];
+
+// ACHIEVEMENTS
+pub const ACHIEVEMENT_ANIMALS_STR: &str = "achievement.animals";
+pub const ACHIEVEMENT_ANIMALS_DESCRIPTION_STR: &str = "achievement.animals.description";
+pub const ACHIEVEMENT_LESSONS_STR: &str = "achievement.lessons";
+pub const ACHIEVEMENT_LESSONS_DESCRIPTION_STR: &str = "achievement.lessons.description";
+pub const ACHIEVEMENT_SECRET_GATE_STR: &str = "achievement.secret_gate";
+pub const ACHIEVEMENT_SECRET_GATE_DESCRIPTION_STR: &str = "achievement.secret_gate.description";
+pub const ACHIEVEMENT_UNLOCKED_STR: &str = "achievement.unlocked";
+pub const ACHIEVEMENT_TOAST_FRAMES: i32 = 240;
+pub const ACHIEVEMENT_TOAST_WIDTH: i32 = 40;
+pub const ACHIEVEMENT_TOAST_HEIGHT: i32 = 3;
+pub const ACHIEVEMENTS_X_FROM_CENTER: i32 = 30;
+pub const ACHIEVEMENTS_FIRST_DELTA_Y: i32 = 4;
+pub const ACHIEVEMENTS_DELTA_Y: i32 = 3;
+pub const ACHIEVEMENT_LOCKED_MARK: &str = "[ ]";
+pub const ACHIEVEMENT_UNLOCKED_MARK: &str = "[x]";
+pub const ACHIEVEMENT_LOCKED_COLOR: RGB = RGB { r: 0.5, g: 0.5, b: 0.5 };
+/// Set in a game once a turn is played with the hints turned on
+pub const HINTS_USED_FLAG: &str = "hints_used";
+
+// HINTS
+pub const HINT_TURNS: u32 = 8;
+pub const HINT_BOX_X: i32 = 1;
+pub const HINT_BOX_Y: i32 = 1;
+pub const HINT_BOX_HEIGHT: i32 = 6;
+pub const HINT_BANNER: &str = "hint.banner";
+pub const HINT_LOCKED_DOOR_STR: &str = "hint.locked_door";
+pub const HINT_NPC_STR: &str = "hint.npc";
+pub const HINT_CRAFTED_STR: &str = "hint.crafted";
+
+// CONTROLS
+pub const KEY_BINDINGS_PATH: &str = "./keybindings.json";
+pub const CONTROLS_ACTION_X_FROM_CENTER: i32 = 24;
+pub const CONTROLS_KEYS_X_FROM_CENTER: i32 = 4;
+pub const CONTROLS_FIRST_DELTA_Y: i32 = 4;
+pub const CONTROLS_DELTA_Y: i32 = 2;
+pub const CONTROLS_HINT_FROM_BOTTOM: i32 = 6;
+pub const CONTROLS_HINT_STR: &str = "controls.hint";
+pub const CONTROLS_CAPTURE_STR: &str = "controls.capture";
+pub const CONTROLS_IN_USE_STR: &str = "controls.in_use";
+pub const ACTION_UP_STR: &str = "action.up";
+pub const ACTION_DOWN_STR: &str = "action.down";
+pub const ACTION_LEFT_STR: &str = "action.left";
+pub const ACTION_RIGHT_STR: &str = "action.right";
+pub const ACTION_UP_LEFT_STR: &str = "action.up_left";
+pub const ACTION_UP_RIGHT_STR: &str = "action.up_right";
+pub const ACTION_DOWN_LEFT_STR: &str = "action.down_left";
+pub const ACTION_DOWN_RIGHT_STR: &str = "action.down_right";
+pub const ACTION_CONFIRM_STR: &str = "action.confirm";
+pub const ACTION_CANCEL_STR: &str = "action.cancel";
+pub const ACTION_RESET_STR: &str = "action.reset";
+pub const ACTION_HISTORY_STR: &str = "action.history";
 
 // OBJECTIVE
-pub const OBJECTIVE_BOX_X: i32 = INVENTORY_X;
-pub const OBJECTIVE_BOX_Y: i32 = PLACE_DATE_BOX_Y - OBJECTIVE_BOX_HEIGHT - 1;
-pub const OBJECTIVE_BOX_WIDTH: i32 = INVENTORY_WIDTH;
-pub const OBJECTIVE_BOX_GAP: i32 = OBJECTIVE_BOX_WIDTH - 4;
-pub const OBJECTIVE_BOX_HEIGHT: i32 = 10;
-pub const OBJECTIVE_BANNER: &str = "Gorev";
-pub const OBJECTIVE_BANNER_X: i32 = OBJECTIVE_BOX_X + OBJECTIVE_BOX_WIDTH / 2 - (OBJECTIVE_BANNER.len() / 2) as i32;
-pub const OBJECTIVE_X: i32 = OBJECTIVE_BOX_X + 2;
-pub const OBJECTIVE_Y: i32 = OBJECTIVE_BOX_Y + 2;
+pub const OBJECTIVE_BOX_Y: i32 = 2;
+pub const OBJECTIVE_BANNER: &str = "objective.banner";
 pub const OBJECTIVE_DELTA_Y: i32 = 2;
 // PLACE DATE
-pub const PLACE_HOME_NAME: &str = "Ev";
-pub const PLACE_SCHOOL_SOUTH_NAME: &str = "Bogazici Guney Kampus";
-pub const PLACE_SCHOOL_NORTH_NAME: &str = "Bogazici Kuzey Kampus";
-pub const PLACE_CLASS_NAME: &str = "M 2152";
-pub const PLACE_LIB_NAME: &str = "Bogazici Olmayan Kutuphane";
-pub const PLACE_OTTOMAN_MAIN_NAME: &str = "Istanbul, Meydan";
-pub const PLACE_OTTOMAN_LEFT_NAME: &str = "Istiklal";
-pub const PLACE_OTTOMAN_RIGHT_NAME: &str = "Uskudar";
-pub const PLACE_OTTOMAN_TOP_NAME: &str = "Sariyer";
+pub const PLACE_HOME_NAME: &str = "place.home";
+pub const PLACE_SCHOOL_SOUTH_NAME: &str = "place.school_south";
+pub const PLACE_SCHOOL_NORTH_NAME: &str = "place.school_north";
+pub const PLACE_CLASS_NAME: &str = "place.class";
+pub const PLACE_LIB_NAME: &str = "place.library";
+pub const PLACE_OTTOMAN_MAIN_NAME: &str = "place.ottoman_main";
+pub const PLACE_OTTOMAN_LEFT_NAME: &str = "place.ottoman_left";
+pub const PLACE_OTTOMAN_RIGHT_NAME: &str = "place.ottoman_right";
+pub const PLACE_OTTOMAN_TOP_NAME: &str = "place.ottoman_top";
+pub const PLACE_OTTOMAN_BOTTOM_NAME: &str = "place.ottoman_bottom";
 pub const CURRENT_DATE: &str = "2023";
 pub const PAST_DATE: &str = "1952";
-pub const PLACE_DATE_BOX_X: i32 = INVENTORY_X;
-pub const PLACE_DATE_BOX_Y: i32 = INVENTORY_Y - PLACE_DATE_BOX_HEIGHT - 1;
-pub const PLACE_DATE_BOX_WIDTH: i32 = INVENTORY_WIDTH;
+
+// CLOCK
+pub const MINUTES_PER_DAY: u64 = 24 * 60;
+pub const CLOCK_START_MINUTES: u64 = 8 * 60;
+pub const CLOCK_MINUTES_PER_MOVE: u64 = 1;
+pub const CLOCK_MINUTES_PER_DIALOGUE_PAGE: u64 = 2;
+
+// ERA TRANSITION
+pub const ERA_TRANSITION_FRAMES: i32 = 45;
 pub const PLACE_DATE_BOX_HEIGHT: i32 = 6;
-pub const PLACE_DATE_BOX_GAP: i32 = PLACE_DATE_BOX_WIDTH - 4;
-pub const PLACE_DATE_BANNER: &str = "Mekan/Zaman";
-pub const PLACE_DATE_BANNER_X: i32 = PLACE_DATE_X + PLACE_DATE_BOX_WIDTH / 2 - (PLACE_DATE_BANNER.len() / 2) as i32;
-pub const PLACE_DATE_X: i32 = PLACE_DATE_BOX_X + 2;
-pub const PLACE_DATE_Y: i32 = PLACE_DATE_BOX_Y + 2;
+pub const PLACE_DATE_BANNER: &str = "place_date.banner";
 pub const PLACE_DATE_DELTA_Y: i32 = 2;
+pub const PLACE_DATE_SEED_STR: &str = "place_date.seed";
 // INVENTORY
-pub const INVENTORY_X: i32 = MAP_WIDTH;
-pub const INVENTORY_Y: i32 = MAP_HEIGHT - INVENTORY_HEIGHT - 1;
 pub const INVENTORY_DELTA_Y: i32 = 2;
-pub const INVENTORY_WIDTH: i32 = SCREEN_WIDTH - MAP_WIDTH - 1;
+/// Width of the inventory when it opens over the map, the side panel sets it otherwise
+pub const INVENTORY_OVERLAY_WIDTH: i32 = 29;
 pub const INVENTORY_HEIGHT: i32 = 19;
-pub const INVENTORY_BANNER: &str = "Esyalar";
-pub const INVENTORY_BANNER_X: i32 = INVENTORY_X + INVENTORY_WIDTH / 2 - (INVENTORY_BANNER.len() / 2) as i32;
-pub const INVENTORY_ITEMS_X: i32 = INVENTORY_X + 2 * INVENTORY_DELTA_Y;
-pub const INVENTORY_ITEMS_Y: i32 = INVENTORY_Y + INVENTORY_DELTA_Y;
+pub const TOOLTIP_LOCKED_STR: &str = "tooltip.locked";
+pub const TOOLTIP_OPEN_STR: &str = "tooltip.open";
+pub const INVENTORY_BANNER: &str = "inventory.banner";
 // ITEMS
-pub const ITEM_BOOK_NAME: &str = "Taylan Hoca'nin Kitabi";
-pub const ITEM_SECRET_GATE_KEY_NAME: &str = "Gizli Gecit Anahtari";
-pub const OTTOMAN_KEY_1_NAME: &str = "Bati Anahtari";
-pub const OTTOMAN_REWARD_POEM_NAME: &str = "Siir Parcasi";
-pub const OTTOMAN_REWARD_BOOK_COVER_NAME: &str = "Kitap Kapagi";
-pub const OTTOMAN_REWARD_GLUE_NAME: &str = "Yapistirici";
-pub const OTTOMAN_COMBINED_REWARD_POEM_BOOK_NAME: &str = "Siir Kitabi";
-pub const OTTOMAN_KEY_2_NAME: &str = "Kuzey Anahtari";
-pub const OTTOMAN_REWARD_MOSQUE_PART_1_NAME: &str = "Camii Minare Maketi";
-pub const OTTOMAN_REWARD_MOSQUE_PART_2_NAME: &str = "Camii Gövde Maketi";
-pub const OTTOMAN_COMBINED_REWARD_MOSQUE_MODEL_NAME: &str = "Camii Maketi";
-pub const OTTOMAN_KEY_3_NAME: &str = "Dogu Anahtari";
-pub const OTTOMAN_REWARD_NOTE_PAPER_NAME: &str = "Nota Kagidi";
-pub const OTTOMAN_REWARD_CANVAS_NAME: &str = "Tuval";
-pub const OTTOMAN_REWARD_CLAY_NAME: &str = "Kil";
-pub const OTTOMAN_COMBINED_REWARD_WEIRD_COLLAGE_NAME: &str = "Garip Bir Kolaj";
-pub const OTTOMAN_KEY_MAIN_NAME: &str = "Zaman Kapisi Anahtari";
+pub const ITEM_BOOK_NAME: &str = "item.book";
+pub const ITEM_SECRET_GATE_KEY_NAME: &str = "item.secret_gate_key";
+pub const OTTOMAN_KEY_1_NAME: &str = "item.ottoman_key_1";
+pub const OTTOMAN_REWARD_POEM_NAME: &str = "item.poem";
+pub const OTTOMAN_REWARD_BOOK_COVER_NAME: &str = "item.book_cover";
+pub const OTTOMAN_REWARD_GLUE_NAME: &str = "item.glue";
+pub const OTTOMAN_COMBINED_REWARD_POEM_BOOK_NAME: &str = "item.poem_book";
+pub const OTTOMAN_KEY_2_NAME: &str = "item.ottoman_key_2";
+pub const OTTOMAN_REWARD_MOSQUE_PART_1_NAME: &str = "item.mosque_part_1";
+pub const OTTOMAN_REWARD_MOSQUE_PART_2_NAME: &str = "item.mosque_part_2";
+pub const OTTOMAN_COMBINED_REWARD_MOSQUE_MODEL_NAME: &str = "item.mosque_model";
+pub const OTTOMAN_KEY_3_NAME: &str = "item.ottoman_key_3";
+pub const OTTOMAN_REWARD_NOTE_PAPER_NAME: &str = "item.note_paper";
+pub const OTTOMAN_REWARD_CANVAS_NAME: &str = "item.canvas";
+pub const OTTOMAN_REWARD_CLAY_NAME: &str = "item.clay";
+pub const OTTOMAN_COMBINED_REWARD_WEIRD_COLLAGE_NAME: &str = "item.weird_collage";
+pub const OTTOMAN_KEY_MAIN_NAME: &str = "item.time_gate_key";
+pub const ITEM_PET_FOOD_NAME: &str = "item.pet_food";
 // NPC INTERACTION
 pub const NPC_INTERACTION_SCREEN_WIDTH: i32 = 40;
 pub const NPC_INTERACTION_SCREEN_HEIGHT: i32 = 44;
-pub const NPC_INTERACTION_SCREEN_GAP_WIDTH: i32 = NPC_INTERACTION_SCREEN_WIDTH - 3 * NPC_INTERACTION_DIALOGUE_DELTA;
-pub const NPC_INTERACTION_SCREEN_X: i32 = (MAP_WIDTH - NPC_INTERACTION_SCREEN_WIDTH) / 2;
-pub const NPC_INTERACTION_SCREEN_Y: i32 = (MAP_HEIGHT - NPC_INTERACTION_SCREEN_HEIGHT + 4) / 2;
 pub const NPC_INTERACTION_DIALOGUE_DELTA: i32 = 2;
-pub const NPC_INTERACTION_DIALOGUE_HEADING_X: i32 = NPC_INTERACTION_SCREEN_X + NPC_INTERACTION_SCREEN_WIDTH / 2;
-pub const NPC_INTERACTION_DIALOGUE_HEADING_Y: i32 = NPC_INTERACTION_SCREEN_Y + NPC_INTERACTION_DIALOGUE_DELTA;
-pub const NPC_INTERACTION_GLYPH_X: i32 = NPC_INTERACTION_SCREEN_X + NPC_INTERACTION_DIALOGUE_DELTA;
-pub const NPC_INTERACTION_DIALOGUE_X: i32 = NPC_INTERACTION_GLYPH_X + NPC_INTERACTION_DIALOGUE_DELTA;
-pub const NPC_INTERACTION_DIALOGUE_Y: i32 = NPC_INTERACTION_DIALOGUE_HEADING_Y + NPC_INTERACTION_DIALOGUE_DELTA;
 // ROOMS
 // HOME
 pub const HOME_X: i32 = (MAP_WIDTH - HOME_WIDTH) / 2;
@@ -193,10 +364,52 @@ pub const OTTOMAN_BOTTOM_WIDTH: i32 = 12;
 pub const OTTOMAN_BOTTOM_HEIGHT: i32 = 12;
 pub const OTTOMAN_BOTTOM_TO_MAIN_PORTAL_COORD: (i32, i32) = (OTTOMAN_BOTTOM_X + OTTOMAN_BOTTOM_WIDTH / 2, OTTOMAN_BOTTOM_Y - 1);
 pub const OTTOMAN_BOTTOM_FROM_MAIN_COORD: (i32, i32) = (OTTOMAN_BOTTOM_TO_MAIN_PORTAL_COORD.0, OTTOMAN_BOTTOM_TO_MAIN_PORTAL_COORD.1 + 1);
+// GENERATED STREETS
+pub const GENERATED_AREA_X: i32 = 4;
+pub const GENERATED_AREA_Y: i32 = 4;
+pub const GENERATED_AREA_WIDTH: i32 = MAP_WIDTH - 2 * GENERATED_AREA_X;
+pub const GENERATED_AREA_HEIGHT: i32 = MAP_HEIGHT - 2 * GENERATED_AREA_Y;
+pub const GENERATED_MAX_ROOMS: i32 = 12;
+pub const GENERATED_ROOM_MIN_SIZE: i32 = 5;
+pub const GENERATED_ROOM_MAX_SIZE: i32 = 9;
+// NPC MOVEMENT
+/// A wandering animal moves on average once every this many player turns
+pub const NPC_WANDER_CHANCE: i32 = 3;
+// COMPANION
+pub const COMPANION_SNIFF_DISTANCE: i32 = 3;
+pub const COMPANION_REACTION_COOLDOWN: i32 = 15;
 // CONSOLE OUTPUTS
-pub const CONSOLE_ITEM_USED: &str = "Esya kullanildi:";
-pub const CONSOLE_ITEM_NOT_EXIST: &str = "Gerekli esyan yok";
-pub const CONSOLE_ITEM_ACQUIRED: &str = "Esyayi aldin:";
+pub const CONSOLE_WELCOME: &str = "console.welcome";
+pub const CONSOLE_WRONG_ITEM: &str = "console.wrong_item";
+pub const CONSOLE_ITEM_USED: &str = "console.item_used";
+pub const CONSOLE_ITEM_NOT_EXIST: &str = "console.item_not_exist";
+pub const CONSOLE_ITEM_ACQUIRED: &str = "console.item_acquired";
+pub const CONSOLE_ITEM_CRAFTED: &str = "console.item_crafted";
+pub const CONSOLE_TIME_TRAVEL: &str = "console.time_travel";
+pub const CONSOLE_COMPANION_BEFRIENDED: &str = "console.companion_befriended";
+pub const CONSOLE_COMPANION_SNIFF: &str = "console.companion_sniff";
+pub const CONSOLE_DIALOGUE_FINISHED: &str = "console.dialogue_finished";
+pub const CONSOLE_NEW_OBJECTIVE: &str = "console.new_objective";
+pub const CONSOLE_WRITE_FAILED: &str = "console.write_failed";
+// GAME LOG
+pub const GAME_LOG_CAPACITY: usize = 200;
+pub const LOG_CATEGORY_ITEM_STR: &str = "log.category.item";
+pub const LOG_CATEGORY_DIALOGUE_STR: &str = "log.category.dialogue";
+pub const LOG_CATEGORY_QUEST_STR: &str = "log.category.quest";
+pub const LOG_CATEGORY_SYSTEM_STR: &str = "log.category.system";
+pub const LOG_HISTORY_TITLE_STR: &str = "log.title";
+pub const LOG_HISTORY_FILTER_STR: &str = "log.filter";
+pub const LOG_HISTORY_ALL_STR: &str = "log.all";
+pub const LOG_HISTORY_EMPTY_STR: &str = "log.empty";
+pub const LOG_HISTORY_HINT_STR: &str = "log.hint";
+pub const LOG_HISTORY_X: i32 = 4;
+/// Room for the turn and the time in front of an entry
+pub const LOG_HISTORY_STAMP_WIDTH: i32 = 11;
+pub const LOG_HISTORY_TITLE_Y: i32 = 2;
+pub const LOG_HISTORY_FILTER_Y: i32 = 4;
+pub const LOG_HISTORY_FIRST_Y: i32 = 6;
+pub const LOG_HISTORY_LINES_FROM_BOTTOM: i32 = 4;
+pub const LOG_HISTORY_HINT_FROM_BOTTOM: i32 = 2;
 
 // COLORS
 pub const BACKGROUND_COLOR: RGB = RGB { r: 0., g: 0., b: 0. };
@@ -216,12 +429,20 @@ pub const ITEM_PORTAL_COLOR: RGB = RGB { r: 21f32 / 255.0, g: 246f32 / 255.0, b:
 pub const SPACE_COLOR: RGB = RGB { r: 131f32 / 255.0, g: 131f32 / 255.0, b: 131f32 / 255.0 };
 pub const TILE_COLOR: RGB = RGB { r: 188f32 / 255.0, g: 188f32 / 255.0, b: 188f32 / 255.0 };
 pub const WALL_COLOR: RGB = RGB { r: 130f32 / 255.0, g: 130f32 / 255.0, b: 130f32 / 255.0 };
+pub const PAST_SPACE_COLOR: RGB = RGB { r: 140f32 / 255.0, g: 112f32 / 255.0, b: 78f32 / 255.0 };
+pub const PAST_TILE_COLOR: RGB = RGB { r: 204f32 / 255.0, g: 176f32 / 255.0, b: 128f32 / 255.0 };
+pub const PAST_WALL_COLOR: RGB = RGB { r: 125f32 / 255.0, g: 94f32 / 255.0, b: 60f32 / 255.0 };
+pub const PAST_SEPIA_AMOUNT: f32 = 0.7;
 // COLORS CURSOR
 pub const CURSOR_COLOR: RGB = RGB { r: 242f32 / 255.0, g: 47f32 / 255.0, b: 196f32 / 255.0 };
 // COLORS CONSOLE
 pub const CONSOLE_BORDER_COLOR: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
 pub const CONSOLE_BACKGROUND_COLOR: RGB = RGB { r: 0., g: 0., b: 0. };
-pub const CONSOLE_LOG_COLOR: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
+pub const LOG_ITEM_COLOR: RGB = RGB { r: 238f32 / 255.0, g: 253f32 / 255.0, b: 28f32 / 255.0 };
+pub const LOG_DIALOGUE_COLOR: RGB = RGB { r: 120f32 / 255.0, g: 200f32 / 255.0, b: 1.0 };
+pub const LOG_QUEST_COLOR: RGB = RGB { r: 21f32 / 255.0, g: 246f32 / 255.0, b: 111f32 / 255.0 };
+pub const LOG_SYSTEM_COLOR: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
+pub const LOG_TURN_COLOR: RGB = RGB { r: 131f32 / 255.0, g: 131f32 / 255.0, b: 131f32 / 255.0 };
 // COLORS OBJECTIVE
 pub const OBJECTIVE_BOX_FG: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
 pub const OBJECTIVE_BOX_BG: RGB = RGB { r: 0., g: 0., b: 0. };
@@ -230,11 +451,10 @@ pub const OBJECTIVE_BANNER_COLOR: RGB = RGB { r: 238f32 / 255.0, g: 253f32 / 255
 pub const PLACE_DATE_BOX_FG: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
 pub const PLACE_DATE_BOX_BG: RGB = RGB { r: 0., g: 0., b: 0. };
 pub const PLACE_DATE_BANNER_COLOR: RGB = RGB { r: 238f32 / 255.0, g: 253f32 / 255.0, b: 28f32 / 255.0 };
-pub const PLACE_DATE_COLOR: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
+pub const PLACE_DATE_SEED_COLOR: RGB = RGB { r: 238f32 / 255.0, g: 253f32 / 255.0, b: 28f32 / 255.0 };
 // COLORS INVENTORY
 pub const INVENTORY_BORDER_COLOR: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
 pub const INVENTORY_BACKGROUND_COLOR: RGB = RGB { r: 0., g: 0., b: 0. };
-pub const INVENTORY_BANNER_COLOR: RGB = RGB { r: 238f32 / 255.0, g: 253f32 / 255.0, b: 28f32 / 255.0 };
 pub const INVENTORY_STRING_COLOR: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
 // COLORS NPC INTERACTION
 pub const NPC_INTERACTION_SCREEN_FG: RGB = RGB { r: 1.0, g: 1.0, b: 1.0 };
@@ -0,0 +1,31 @@
+use specs::{Dispatcher, DispatcherBuilder};
+use crate::systems::{AchievementSystem, ClockSystem, CodexSystem, CompanionSystem, EraTransitionSystem, EventLogSystem, HintSystem, ItemAdjustmentSystem, ItemCollectionSystem, ItemCombinationSystem, LearningRecordSystem, NpcMovementSystem, ObjectiveSystem, StatisticsSystem, TriggerSystem, TurnEndSystem};
+
+/// Systems of an in-game tick. New systems are registered here with the systems they have to
+/// run after, the ones that don't depend on each other may run in parallel.
+pub fn build_game_dispatcher() -> Dispatcher<'static, 'static> {
+    DispatcherBuilder::new()
+        .with(ItemCollectionSystem {}, "item_collection", &[])
+        .with(ItemAdjustmentSystem {}, "item_adjustment", &[])
+        .with(TriggerSystem {}, "trigger", &["item_collection"])
+        .with(ItemCombinationSystem {}, "item_combination", &["item_collection", "trigger"])
+        .with(NpcMovementSystem {}, "npc_movement", &["trigger"])
+        .with(CompanionSystem {}, "companion", &["npc_movement"])
+        .with(ClockSystem {}, "clock", &[])
+        .with(TurnEndSystem {}, "turn_end", &["npc_movement", "companion", "clock"])
+        .build()
+}
+
+/// Consumers of the game events, run at the end of every in-game tick before the events are cleared
+pub fn build_event_dispatcher() -> Dispatcher<'static, 'static> {
+    DispatcherBuilder::new()
+        .with(EventLogSystem {}, "event_log", &[])
+        .with(ObjectiveSystem {}, "objective", &["event_log"])
+        .with(EraTransitionSystem {}, "era_transition", &[])
+        .with(HintSystem {}, "hint", &[])
+        .with(CodexSystem {}, "codex", &[])
+        .with(AchievementSystem {}, "achievement", &["codex"])
+        .with(StatisticsSystem {}, "statistics", &[])
+        .with(LearningRecordSystem::default(), "learning_records", &[])
+        .build()
+}
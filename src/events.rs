@@ -0,0 +1,31 @@
+use specs::Entity;
+use crate::items::ItemName;
+use crate::Place;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum GameEvent {
+    /// Picked up from the ground or handed over by an NPC
+    ItemPicked(ItemName),
+    /// Given to an NPC or used on a door
+    ItemUsed(ItemName),
+    PlaceEntered(Place),
+    DialogueStarted(Entity),
+    DialogueFinished(Entity),
+    ItemCrafted(ItemName),
+    DoorUnlocked(Entity),
+    /// A dialogue moved the player on to the next objective
+    ObjectiveAdvanced,
+}
+
+/// Events of the current tick; anything can write them, the consumer systems read them at the
+/// end of the tick before they are cleared
+#[derive(Default)]
+pub struct GameEvents {
+    pub events: Vec<GameEvent>,
+}
+
+impl GameEvents {
+    pub fn push(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+}
@@ -1,9 +1,69 @@
+use std::collections::VecDeque;
+use rltk::RGB;
+use crate::clock::GameClock;
+use crate::constants::{CONSOLE_WRITE_FAILED, GAME_LOG_CAPACITY, LOG_CATEGORY_DIALOGUE_STR, LOG_CATEGORY_ITEM_STR, LOG_CATEGORY_QUEST_STR, LOG_CATEGORY_SYSTEM_STR, LOG_DIALOGUE_COLOR, LOG_ITEM_COLOR, LOG_QUEST_COLOR, LOG_SYSTEM_COLOR};
+use crate::i18n::tr;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum LogCategory {
+    Item,
+    Dialogue,
+    Quest,
+    System,
+}
+
+impl LogCategory {
+    pub const ALL: [LogCategory; 4] = [LogCategory::Item, LogCategory::Dialogue, LogCategory::Quest, LogCategory::System];
+
+    pub fn get_name(&self) -> String {
+        tr(match self {
+            LogCategory::Item => LOG_CATEGORY_ITEM_STR,
+            LogCategory::Dialogue => LOG_CATEGORY_DIALOGUE_STR,
+            LogCategory::Quest => LOG_CATEGORY_QUEST_STR,
+            LogCategory::System => LOG_CATEGORY_SYSTEM_STR,
+        })
+    }
+
+    pub fn color(&self) -> RGB {
+        match self {
+            LogCategory::Item => LOG_ITEM_COLOR,
+            LogCategory::Dialogue => LOG_DIALOGUE_COLOR,
+            LogCategory::Quest => LOG_QUEST_COLOR,
+            LogCategory::System => LOG_SYSTEM_COLOR,
+        }
+    }
+}
+
+pub struct LogEntry {
+    pub category: LogCategory,
+    /// Already translated, the log shows what was said in the language of the moment
+    pub text: String,
+    pub turn: u64,
+    /// Time of day in the game when the entry was written
+    pub time: String,
+}
+
+/// Messages to the player, only the newest `GAME_LOG_CAPACITY` of them are kept
+#[derive(Default)]
 pub struct GameLog {
-    pub entries: Vec<String>,
+    pub entries: VecDeque<LogEntry>,
 }
 
 impl GameLog {
-    pub fn new(entries: Vec<String>) -> Self {
-        Self { entries }
+    pub fn push(&mut self, category: LogCategory, clock: &GameClock, text: String) {
+        self.entries.push_back(LogEntry { category, text, turn: clock.turns, time: clock.time_of_day() });
+        while self.entries.len() > GAME_LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+    }
+
+    /// A file of the game could not be written, the game goes on without it
+    pub fn push_write_failure(&mut self, clock: &GameClock, path: &str) {
+        self.push(LogCategory::System, clock, format!("{} {}", tr(CONSOLE_WRITE_FAILED), path));
+    }
+
+    /// Oldest first
+    pub fn filtered(&self, filter: Option<LogCategory>) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().filter(move |entry| filter.is_none_or(|category| entry.category == category))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,73 @@
+use rltk::{FontCharType, Rltk, RGB};
+use crate::constants::{TURKISH_FALLBACK_CHARS, TURKISH_FONT_CHARS, TYPOGRAPHIC_FALLBACK_CHARS, KEY_CHAR, KEY_FALLBACK_CHAR, LOCKED_DOOR_CHAR, LOCKED_DOOR_FALLBACK_CHAR, NPC_CAT_CHAR, NPC_CAT_FALLBACK_CHAR, NPC_DOG_CHAR, NPC_DOG_FALLBACK_CHAR, NPC_HUMAN_CHAR, NPC_HUMAN_FALLBACK_CHAR, PLAYER_CHAR, PLAYER_FALLBACK_CHAR, PORTAL_CHAR, PORTAL_FALLBACK_CHAR};
+
+/// The glyph to put on the screen. Terminals can't be relied on to have the CP437 symbols, so the
+/// terminal build swaps them, and the box drawing walls, for plain ASCII.
+pub fn display_glyph(glyph: FontCharType) -> FontCharType {
+    if !cfg!(feature = "terminal") { return glyph; }
+    let fallback = match glyph {
+        g if g == rltk::to_cp437(PLAYER_CHAR) => PLAYER_FALLBACK_CHAR,
+        g if g == rltk::to_cp437(KEY_CHAR) => KEY_FALLBACK_CHAR,
+        g if g == rltk::to_cp437(PORTAL_CHAR) => PORTAL_FALLBACK_CHAR,
+        g if g == rltk::to_cp437(LOCKED_DOOR_CHAR) => LOCKED_DOOR_FALLBACK_CHAR,
+        g if g == rltk::to_cp437(NPC_HUMAN_CHAR) => NPC_HUMAN_FALLBACK_CHAR,
+        g if g == rltk::to_cp437(NPC_DOG_CHAR) => NPC_DOG_FALLBACK_CHAR,
+        g if g == rltk::to_cp437(NPC_CAT_CHAR) => NPC_CAT_FALLBACK_CHAR,
+        9 => 'o',
+        186 => '|',
+        205 => '-',
+        185 | 187 | 188 | 200..=204 | 206 => '+',
+        _ => return glyph,
+    };
+    rltk::to_cp437(fallback)
+}
+
+/// Swaps the Turkish letters missing from CP437 for the cells our font draws them in, or for
+/// their plain ASCII letters in the terminal build, where the font can't be changed. Curly quotes
+/// have no cell in either, they become straight ones.
+pub fn printable(text: &str) -> String {
+    let table = if cfg!(feature = "terminal") { &TURKISH_FALLBACK_CHARS } else { &TURKISH_FONT_CHARS };
+    text.chars()
+        .map(|c| table.iter().chain(TYPOGRAPHIC_FALLBACK_CHARS.iter()).find(|(letter, _)| *letter == c).map(|(_, cell)| *cell).unwrap_or(c))
+        .collect()
+}
+
+/// Width of the text on the screen, every letter takes one cell however many bytes it is
+pub fn text_width(text: &str) -> i32 {
+    text.chars().count() as i32
+}
+
+/// Printing that goes through `printable` and centers by letters instead of bytes, the text
+/// functions of the console would leave the Turkish letters blank and shift centered lines
+pub trait PrintText {
+    fn print_text<T: AsRef<str>>(&mut self, x: i32, y: i32, text: T);
+    fn print_text_centered<T: AsRef<str>>(&mut self, y: i32, text: T);
+    fn print_color_text<T: AsRef<str>>(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: T);
+    fn print_color_text_centered<T: AsRef<str>>(&mut self, y: i32, fg: RGB, bg: RGB, text: T);
+    fn print_color_text_centered_at<T: AsRef<str>>(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: T);
+}
+
+impl PrintText for Rltk {
+    fn print_text<T: AsRef<str>>(&mut self, x: i32, y: i32, text: T) {
+        self.print(x, y, printable(text.as_ref()));
+    }
+
+    fn print_text_centered<T: AsRef<str>>(&mut self, y: i32, text: T) {
+        let x = self.get_char_size().0 as i32 / 2 - text_width(text.as_ref()) / 2;
+        self.print_text(x, y, text);
+    }
+
+    fn print_color_text<T: AsRef<str>>(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: T) {
+        self.print_color(x, y, fg, bg, printable(text.as_ref()));
+    }
+
+    fn print_color_text_centered<T: AsRef<str>>(&mut self, y: i32, fg: RGB, bg: RGB, text: T) {
+        let x = self.get_char_size().0 as i32 / 2;
+        self.print_color_text_centered_at(x, y, fg, bg, text);
+    }
+
+    fn print_color_text_centered_at<T: AsRef<str>>(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, text: T) {
+        let x = x - text_width(text.as_ref()) / 2;
+        self.print_color_text(x, y, fg, bg, text);
+    }
+}
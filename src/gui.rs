@@ -1,10 +1,24 @@
-use rltk::{RGB, Rltk, Point, WHITE, BLACK, VirtualKeyCode, RED, GREY, YELLOW};
+use rltk::{RGB, Rltk, Point, WHITE, BLACK, VirtualKeyCode, RED, GREY, YELLOW, BEvent, INPUT};
 use specs::prelude::*;
-use crate::{BelongsTo, ContainsItems, Interaction, Item, Map, Name, Npc, Objective, Place, PlayerName, Portal, Position, Renderable, RequiresItem, RequiresItems, RunState, save_load_system, State, Stored, TargetedPosition};
-use crate::constants::{BACKGROUND_COLOR, CONSOLE_BACKGROUND_COLOR, CONSOLE_BORDER_COLOR, CREDITS_STR, CURSOR_COLOR, MENU_DELTA_Y, INVENTORY_BACKGROUND_COLOR, INVENTORY_BANNER, INVENTORY_BANNER_COLOR, INVENTORY_BANNER_X, INVENTORY_BORDER_COLOR, INVENTORY_DELTA_Y, INVENTORY_HEIGHT, INVENTORY_ITEMS_X, INVENTORY_STRING_COLOR, INVENTORY_WIDTH, INVENTORY_X, INVENTORY_Y, LOAD_GAME_STR, MAP_HEIGHT, MENU_ITEM_1_Y, MENU_SELECTED_COLOR, MENU_UNSELECTED_COLOR, NEW_GAME_STR, NPC_INTERACTION_DIALOGUE_DELTA, NPC_INTERACTION_DIALOGUE_HEADING_X, NPC_INTERACTION_DIALOGUE_HEADING_Y, NPC_INTERACTION_DIALOGUE_X, NPC_INTERACTION_DIALOGUE_Y, NPC_INTERACTION_GLYPH_X, NPC_INTERACTION_SCREEN_BG, NPC_INTERACTION_SCREEN_FG, NPC_INTERACTION_SCREEN_GAP_WIDTH, NPC_INTERACTION_SCREEN_HEIGHT, NPC_INTERACTION_SCREEN_WIDTH, NPC_INTERACTION_SCREEN_X, NPC_INTERACTION_SCREEN_Y, QUIT_GAME_STR, SCREEN_HEIGHT, SCREEN_WIDTH, TITLE_STR, TITLE_Y, CREDITS_1_COLOR, CREDIT_1_STR, CREDITS_THANKS_Y, CREDIT_3_Y, CREDIT_2_Y, CREDIT_1_Y, CREDITS_3_COLOR, CREDITS_2_COLOR, CREDITS_THANKS_COLOR, CREDIT_2_STR, CREDIT_3_STR, CREDITS_THANKS_STR, PLACE_DATE_BOX_X, PLACE_DATE_BOX_Y, PLACE_DATE_BOX_WIDTH, PLACE_DATE_BOX_HEIGHT, PLACE_DATE_BOX_FG, PLACE_DATE_BOX_BG, PLACE_DATE_X, PLACE_DATE_Y, PLACE_DATE_COLOR, CONSOLE_LOG_COLOR, OBJECTIVE_BOX_GAP, OBJECTIVE_X, OBJECTIVE_Y, OBJECTIVE_DELTA_Y, OBJECTIVE_BOX_X, OBJECTIVE_BOX_Y, OBJECTIVE_BOX_WIDTH, OBJECTIVE_BOX_HEIGHT, OBJECTIVE_BOX_FG, OBJECTIVE_BOX_BG, PLACE_DATE_BOX_GAP, PLACE_DATE_DELTA_Y, PLACE_DATE_BANNER_X, PLACE_DATE_BANNER, PLACE_DATE_BANNER_COLOR, OBJECTIVE_BANNER_X, OBJECTIVE_BANNER, OBJECTIVE_BANNER_COLOR, INVENTORY_ITEMS_Y, CONSOLE_ITEM_ACQUIRED, CONSOLE_ITEM_USED, CONSOLE_ITEM_NOT_EXIST};
-use crate::gamelog::GameLog;
+use crate::rect::Rect;
+use crate::{BelongsTo, ContainsItems, Interaction, Item, Map, MapGeneration, Name, Npc, Objective, Place, PlayerName, Portal, Position, Renderable, RequiresItem, RequiresItems, RunState, save_load_system, State, Stored, TargetedPosition};
+use crate::constants::{BACKGROUND_COLOR, CONSOLE_BACKGROUND_COLOR, CONSOLE_BORDER_COLOR, CREDITS_STR, CURSOR_COLOR, MENU_DELTA_Y, INVENTORY_BACKGROUND_COLOR, INVENTORY_BANNER, INVENTORY_BORDER_COLOR, INVENTORY_DELTA_Y, INVENTORY_STRING_COLOR, LOAD_GAME_STR, MENU_SELECTED_COLOR, MENU_UNSELECTED_COLOR, NEW_GAME_STR, NPC_INTERACTION_DIALOGUE_DELTA, NPC_INTERACTION_SCREEN_BG, NPC_INTERACTION_SCREEN_FG, QUIT_GAME_STR, CONTROLS_STR, PAUSE_TITLE_STR, PAUSE_RESUME_STR, PAUSE_SAVE_STR, PAUSE_LOAD_STR, PAUSE_SETTINGS_STR, PAUSE_HELP_STR, PAUSE_QUIT_STR, CONFIRM_OVERWRITE_STR, CONFIRM_QUIT_STR, CONFIRM_HINT_STR, SETTINGS_TRANSITION_STR, SETTINGS_ON_STR, SETTINGS_OFF_STR, HELP_KEYS_DELTA_X, HELP_DELTA_Y, HELP_MARGIN, HELP_EXIT_STR, HELP_CONTROLS_STR, HELP_MECHANICS_STR, HELP_MECHANICS_WIDTH, HELP_MECHANICS, HINT_BANNER, CONTROLS_ACTION_X_FROM_CENTER, CONTROLS_KEYS_X_FROM_CENTER, CONTROLS_FIRST_DELTA_Y, CONTROLS_DELTA_Y, CONTROLS_HINT_STR, CONTROLS_CAPTURE_STR, CONTROLS_IN_USE_STR, TITLE_STR, CREDITS_1_COLOR, CREDIT_1_STR, CREDITS_3_COLOR, CREDITS_2_COLOR, CREDITS_THANKS_COLOR, CREDIT_2_STR, CREDIT_3_STR, CREDITS_THANKS_STR, PLACE_DATE_BOX_FG, PLACE_DATE_BOX_BG, OBJECTIVE_DELTA_Y, OBJECTIVE_BOX_FG, OBJECTIVE_BOX_BG, PLACE_DATE_DELTA_Y, PLACE_DATE_BANNER, PLACE_DATE_BANNER_COLOR, OBJECTIVE_BANNER, OBJECTIVE_BANNER_COLOR, CONSOLE_ITEM_NOT_EXIST, PLACE_DATE_SEED_STR, PLACE_DATE_SEED_COLOR, ENTER_NAME_STR, GAME_WON_STR, SETTINGS_LANGUAGE_STR, PROFILE_MENU_STR, PROFILES_CURRENT_STR, PROFILES_FIRST_DELTA_Y, PROFILES_LECTURES_STR, PROFILES_NEW_STR, PROFILES_PLAYTHROUGHS_STR, PROFILES_TITLE_STR, SETTINGS_COLORS_STR, SETTINGS_HIGH_CONTRAST_STR, SETTINGS_MARKERS_STR, SETTINGS_TILE_SIZE_STR, SETTINGS_HINTS_STR, SETTINGS_LEARNING_RECORDS_STR, ACHIEVEMENTS_STR, ACHIEVEMENTS_X_FROM_CENTER, ACHIEVEMENTS_FIRST_DELTA_Y, ACHIEVEMENTS_DELTA_Y, ACHIEVEMENT_LOCKED_COLOR, ACHIEVEMENT_LOCKED_MARK, ACHIEVEMENT_UNLOCKED_MARK, ACHIEVEMENT_UNLOCKED_STR, REPORT_COMMENT_WIDTH, EXPORT_SAVED_STR, EXPORT_FAILED_STR, REPORT_EXPORT_STR, REPORT_FILE, REPORT_FIRST_DELTA_Y, REPORT_LABEL_X_FROM_CENTER, REPORT_MAIN_MENU_STR, REPORT_VALUE_X_FROM_CENTER, STUDY_SHEET_EXPORT_STR, STUDY_SHEET_FILE, TILE_PIXEL_SIZES, KEY_BINDINGS_PATH, MENU_MARKER_LEFT, MENU_MARKER_RIGHT, TOOLTIP_LOCKED_STR, TOOLTIP_OPEN_STR, PLAYER_NAME_MAX_LENGTH, LOG_HISTORY_ALL_STR, LOG_HISTORY_EMPTY_STR, LOG_HISTORY_FILTER_STR, LOG_HISTORY_FIRST_Y, LOG_HISTORY_FILTER_Y, LOG_HISTORY_HINT_STR, LOG_HISTORY_STAMP_WIDTH, LOG_HISTORY_TITLE_STR, LOG_HISTORY_TITLE_Y, LOG_HISTORY_X, LOG_TURN_COLOR, CREDITS_DELTA_Y, CREDITS_THANKS_DELTA_Y, CONTROLS_HINT_FROM_BOTTOM, HELP_EXIT_FROM_BOTTOM, LOG_HISTORY_LINES_FROM_BOTTOM, LOG_HISTORY_HINT_FROM_BOTTOM};
+use crate::achievements::{Achievement, AchievementToasts};
+use crate::clock::GameClock;
+use crate::glyphs::{display_glyph, PrintText, text_width};
+use crate::keybindings::{Action, key_name, KeyBindings, RebindResult};
+use crate::layout::{Layout, ScreenSize};
+use crate::profiles::{save_settings, Profiles};
+use crate::settings::Settings;
+use crate::events::{GameEvent, GameEvents};
+use crate::gamelog::{GameLog, LogCategory};
+use crate::hints::Hints;
+use crate::i18n::{language, tr};
 use crate::items::ItemName;
 use crate::npcs::NpcState;
+use crate::palette::EraTransition;
+use crate::report::{lecturers, Report};
+use crate::save_load_system::LastSave;
 
 #[derive(PartialEq, Copy, Clone)]
 pub enum ItemMenuResult { Cancel, NoResponse, Selected }
@@ -13,6 +27,11 @@ pub enum ItemMenuResult { Cancel, NoResponse, Selected }
 pub enum MainMenuSelection {
     NewGame,
     LoadGame,
+    Profile,
+    Achievements,
+    Settings,
+    Controls,
+    Language,
     QuitGame,
     Credits,
 }
@@ -26,92 +45,131 @@ pub enum MainMenuResult {
 #[derive(PartialEq, Copy, Clone)]
 pub enum NpcInteractionResult { NoResponse, Done, NextDialogue { index: usize } }
 
-fn print_as_paragraph(ctx: &mut Rltk, line: &str, width: usize, x_coord: i32, y_coord: i32, delta_y: i32) -> i32 {
-    let mut y = y_coord;
+#[derive(PartialEq, Copy, Clone)]
+pub enum PauseSelection {
+    Resume,
+    Save,
+    Load,
+    Settings,
+    Help,
+    QuitToMenu,
+}
+
+/// Question shown before an action that loses progress
+#[derive(PartialEq, Copy, Clone)]
+pub enum PauseConfirm {
+    OverwriteSave,
+    QuitWithoutSaving,
+}
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum PauseMenuResult {
+    NoResponse { selected: PauseSelection, confirm: Option<PauseConfirm> },
+    Selected { selected: PauseSelection },
+}
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum SettingsResult {
+    NoResponse { selection: usize },
+    Controls,
+    Done,
+}
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum ControlsResult {
+    NoResponse { selection: usize, capturing: bool, conflict: Option<Action> },
+    Done,
+}
+
+/// Breaks the text into lines of at most `width` letters, at the spaces between words
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
     let mut current_line = String::new();
-    for word in line.split_whitespace() {
-        if current_line.len() + word.len() <= width {
+    for word in text.split_whitespace() {
+        if current_line.chars().count() + word.chars().count() <= width {
             if !current_line.is_empty() {
                 current_line.push(' ');
             }
         } else {
-            ctx.print(x_coord, y, &current_line);
-            current_line.clear();
-            y += delta_y;
+            lines.push(std::mem::take(&mut current_line));
         }
         current_line.push_str(word)
     }
-    ctx.print(x_coord, y, &current_line);
+    lines.push(current_line);
+    lines
+}
+
+fn print_as_paragraph(ctx: &mut Rltk, line: &str, width: usize, x_coord: i32, y_coord: i32, delta_y: i32) -> i32 {
+    let mut y = y_coord - delta_y;
+    for current_line in wrap_text(line, width) {
+        y += delta_y;
+        ctx.print_text(x_coord, y, &current_line);
+    }
     y
 }
 
+/// Text and colour of a menu entry, the selected one is also put between markers when the
+/// settings ask for shapes as well as colours
+fn menu_entry(ecs: &World, text: &str, selected: bool) -> (String, RGB) {
+    match (selected, ecs.fetch::<Settings>().markers) {
+        (true, true) => (format!("{}{}{}", MENU_MARKER_LEFT, text, MENU_MARKER_RIGHT), MENU_SELECTED_COLOR),
+        (true, false) => (text.to_string(), MENU_SELECTED_COLOR),
+        (false, _) => (text.to_string(), MENU_UNSELECTED_COLOR),
+    }
+}
+
+/// Whether the mouse is over the text printed centered around `center_x` on the row `y`
+fn mouse_over_centered(ctx: &Rltk, center_x: i32, y: i32, text: &str) -> bool {
+    let (mouse_x, mouse_y) = ctx.mouse_pos();
+    let left_x = center_x - text_width(text) / 2;
+    mouse_y == y && mouse_x >= left_x && mouse_x < left_x + text_width(text)
+}
+
 pub fn draw_main_menu(gs: &mut State, ctx: &mut Rltk) -> MainMenuResult {
     let save_exists = save_load_system::save_exists();
     let state = gs.ecs.fetch::<RunState>();
+    let bindings = gs.ecs.fetch::<KeyBindings>();
+    let layout = gs.ecs.fetch::<Layout>();
 
-    ctx.print_color_centered(TITLE_Y, RGB::named(RED), RGB::named(BLACK), TITLE_STR);
+    ctx.print_color_text_centered(layout.title_y(), RGB::named(RED), RGB::named(BLACK), tr(TITLE_STR));
+    if let Some(profile) = gs.ecs.fetch::<Profiles>().active() {
+        ctx.print_color_text_centered(layout.title_y() + 2, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR, format!("{} {}", tr(PROFILES_CURRENT_STR), profile.name));
+    }
 
-    let mut y = MENU_ITEM_1_Y;
+    let mut options = vec![(MainMenuSelection::NewGame, tr(NEW_GAME_STR))];
+    if save_exists {
+        options.push((MainMenuSelection::LoadGame, tr(LOAD_GAME_STR)));
+    }
+    options.push((MainMenuSelection::Profile, tr(PROFILE_MENU_STR)));
+    options.push((MainMenuSelection::Achievements, tr(ACHIEVEMENTS_STR)));
+    options.push((MainMenuSelection::Settings, tr(PAUSE_SETTINGS_STR)));
+    options.push((MainMenuSelection::Controls, tr(CONTROLS_STR)));
+    options.push((MainMenuSelection::Language, format!("{} {}", tr(SETTINGS_LANGUAGE_STR), language().get_name())));
+    options.push((MainMenuSelection::QuitGame, tr(QUIT_GAME_STR)));
+    options.push((MainMenuSelection::Credits, tr(CREDITS_STR)));
 
     if let RunState::Menu { menu_selection: selected } = *state {
-        ctx.print_color_centered(y, match selected == MainMenuSelection::NewGame {
-            true => MENU_SELECTED_COLOR,
-            false => MENU_UNSELECTED_COLOR
-        }, BACKGROUND_COLOR, NEW_GAME_STR);
-
-        if save_exists {
+        let center_x = layout.center_x();
+        let mut y = layout.menu_y();
+        let mut clicked = None;
+        for (option, text) in options.iter() {
+            let (text, color) = menu_entry(&gs.ecs, text, *option == selected);
+            ctx.print_color_text_centered(y, color, BACKGROUND_COLOR, &text);
+            if ctx.left_click && mouse_over_centered(ctx, center_x, y, &text) {
+                clicked = Some(*option);
+            }
             y += MENU_DELTA_Y;
-            ctx.print_color_centered(y, match selected == MainMenuSelection::LoadGame {
-                true => MENU_SELECTED_COLOR,
-                false => MENU_UNSELECTED_COLOR
-            }, BACKGROUND_COLOR, LOAD_GAME_STR);
         }
 
-        y += MENU_DELTA_Y;
-        ctx.print_color_centered(y, match selected == MainMenuSelection::QuitGame {
-            true => MENU_SELECTED_COLOR,
-            false => MENU_UNSELECTED_COLOR
-        }, BACKGROUND_COLOR, QUIT_GAME_STR);
-
-        y += MENU_DELTA_Y;
-        ctx.print_color_centered(y, match selected == MainMenuSelection::Credits {
-            true => MENU_SELECTED_COLOR,
-            false => MENU_UNSELECTED_COLOR
-        }, BACKGROUND_COLOR, CREDITS_STR);
-
-
-        return match ctx.key {
-            None => MainMenuResult::NoSelection { selected },
-            Some(key) => {
-                match key {
-                    VirtualKeyCode::Up => {
-                        let new_selection = match selected {
-                            MainMenuSelection::NewGame => MainMenuSelection::Credits,
-                            MainMenuSelection::LoadGame => MainMenuSelection::NewGame,
-                            MainMenuSelection::QuitGame => match save_exists {
-                                true => MainMenuSelection::LoadGame,
-                                false => MainMenuSelection::NewGame
-                            }
-                            MainMenuSelection::Credits => MainMenuSelection::QuitGame
-                        };
-                        MainMenuResult::NoSelection { selected: new_selection }
-                    }
-                    VirtualKeyCode::Down => {
-                        let new_selection = match selected {
-                            MainMenuSelection::NewGame => match save_exists {
-                                true => MainMenuSelection::LoadGame,
-                                false => MainMenuSelection::QuitGame
-                            },
-                            MainMenuSelection::LoadGame => MainMenuSelection::QuitGame,
-                            MainMenuSelection::QuitGame => MainMenuSelection::Credits,
-                            MainMenuSelection::Credits => MainMenuSelection::NewGame
-                        };
-                        MainMenuResult::NoSelection { selected: new_selection }
-                    }
-                    VirtualKeyCode::Return => MainMenuResult::Selected { selected },
-                    _ => MainMenuResult::NoSelection { selected }
-                }
-            }
+        if let Some(option) = clicked {
+            return MainMenuResult::Selected { selected: option };
+        }
+        let index = options.iter().position(|(option, _)| *option == selected).unwrap_or(0);
+        return match bindings.action(ctx.key) {
+            Some(Action::Up) => MainMenuResult::NoSelection { selected: options[(index + options.len() - 1) % options.len()].0 },
+            Some(Action::Down) => MainMenuResult::NoSelection { selected: options[(index + 1) % options.len()].0 },
+            Some(Action::Confirm) => MainMenuResult::Selected { selected: options[index].0 },
+            _ => MainMenuResult::NoSelection { selected: options[index].0 }
         };
     }
     MainMenuResult::NoSelection { selected: MainMenuSelection::NewGame }
@@ -119,43 +177,64 @@ pub fn draw_main_menu(gs: &mut State, ctx: &mut Rltk) -> MainMenuResult {
 
 pub fn draw(ecs: &World, ctx: &mut Rltk) {
     draw_ui(ecs, ctx);
-    draw_time_and_date(ecs, ctx);
     draw_tooltips(ecs, ctx);
-    draw_inventory(ecs, ctx);
-    draw_objective(ecs, ctx);
+    if let Some(panel) = ecs.fetch::<Layout>().side_panel {
+        draw_time_and_date(ecs, ctx, &panel.place_date);
+        draw_objective(ecs, ctx, &panel.objective);
+        draw_inventory(ecs, ctx);
+    } else if *ecs.fetch::<RunState>() == RunState::UseInventory {
+        draw_inventory(ecs, ctx);
+    }
+    draw_hint(ecs, ctx);
+    draw_achievement_toast(ecs, ctx);
+}
+
+/// Place, time and objective on one line, for when there is no side panel to show them in
+fn status_line(ecs: &World) -> String {
+    let current_place = ecs.fetch::<Place>();
+    let clock = ecs.fetch::<GameClock>();
+    let objective = ecs.fetch::<Objective>();
+    format!("{}, {}, {} - {}", current_place.get_name(), current_place.get_year(), clock.time_of_day(), tr(&objective.objectives[objective.index]))
 }
 
 fn draw_ui(ecs: &World, ctx: &mut Rltk) {
     // CONSOLE
-    ctx.draw_box(0, MAP_HEIGHT, SCREEN_WIDTH - 1, SCREEN_HEIGHT - MAP_HEIGHT - 1,
+    let layout = ecs.fetch::<Layout>();
+    let console = layout.log;
+    ctx.draw_box(console.x1, console.y1, console.width(), console.height(),
                  CONSOLE_BORDER_COLOR, CONSOLE_BACKGROUND_COLOR);
+    let mut first_y = console.y1 + 1;
+    if layout.side_panel.is_none() {
+        ctx.print_color_text(2, first_y, OBJECTIVE_BANNER_COLOR, CONSOLE_BACKGROUND_COLOR, status_line(ecs));
+        first_y += 1;
+    }
     let log = ecs.fetch::<GameLog>();
-    let mut y = MAP_HEIGHT + 1;
-    for s in log.entries.iter().rev() {
-        if y < SCREEN_HEIGHT - 1 {
-            ctx.print_color(2, y, CONSOLE_LOG_COLOR, CONSOLE_BACKGROUND_COLOR, s);
-        }
-        y += 1;
+    for (entry, y) in log.entries.iter().rev().zip(first_y..console.y2) {
+        ctx.print_color_text(2, y, entry.category.color(), CONSOLE_BACKGROUND_COLOR, &entry.text);
     }
     // MOUSE
     let cursor_pos = ctx.mouse_pos();
     ctx.set_bg(cursor_pos.0, cursor_pos.1, CURSOR_COLOR);
 }
 
-fn draw_objective(ecs: &World, ctx: &mut Rltk) {
+fn draw_objective(ecs: &World, ctx: &mut Rltk, frame: &Rect) {
     let objective = ecs.fetch::<Objective>();
-    ctx.draw_box(OBJECTIVE_BOX_X, OBJECTIVE_BOX_Y, OBJECTIVE_BOX_WIDTH,
-                 OBJECTIVE_BOX_HEIGHT, OBJECTIVE_BOX_FG, OBJECTIVE_BOX_BG);
-    ctx.print_color(OBJECTIVE_BANNER_X, OBJECTIVE_BOX_Y, OBJECTIVE_BANNER_COLOR, BACKGROUND_COLOR, OBJECTIVE_BANNER);
-    print_as_paragraph(ctx, &objective.objectives[objective.index], OBJECTIVE_BOX_GAP as usize, OBJECTIVE_X, OBJECTIVE_Y, OBJECTIVE_DELTA_Y);
+    ctx.draw_box(frame.x1, frame.y1, frame.width(), frame.height(), OBJECTIVE_BOX_FG, OBJECTIVE_BOX_BG);
+    ctx.print_color_text_centered_at(frame.center.0, frame.y1, OBJECTIVE_BANNER_COLOR, BACKGROUND_COLOR, tr(OBJECTIVE_BANNER));
+    print_as_paragraph(ctx, &tr(&objective.objectives[objective.index]), (frame.width() - 4) as usize, frame.x1 + 2, frame.y1 + 2, OBJECTIVE_DELTA_Y);
 }
 
-fn draw_time_and_date(ecs: &World, ctx: &mut Rltk) {
-    ctx.draw_box(PLACE_DATE_BOX_X, PLACE_DATE_BOX_Y, PLACE_DATE_BOX_WIDTH, PLACE_DATE_BOX_HEIGHT, PLACE_DATE_BOX_FG, PLACE_DATE_BOX_BG);
-    ctx.print_color(PLACE_DATE_BANNER_X, PLACE_DATE_BOX_Y, PLACE_DATE_BANNER_COLOR, BACKGROUND_COLOR, PLACE_DATE_BANNER);
+fn draw_time_and_date(ecs: &World, ctx: &mut Rltk, frame: &Rect) {
+    ctx.draw_box(frame.x1, frame.y1, frame.width(), frame.height(), PLACE_DATE_BOX_FG, PLACE_DATE_BOX_BG);
+    ctx.print_color_text_centered_at(frame.center.0, frame.y1, PLACE_DATE_BANNER_COLOR, BACKGROUND_COLOR, tr(PLACE_DATE_BANNER));
     let current_place = ecs.fetch::<Place>();
-    let place_name_year_str = format!("{}, {}", current_place.get_name(), current_place.get_year());
-    print_as_paragraph(ctx, place_name_year_str.as_str(), PLACE_DATE_BOX_GAP as usize, PLACE_DATE_X, PLACE_DATE_Y, PLACE_DATE_DELTA_Y);
+    let clock = ecs.fetch::<GameClock>();
+    let place_name_year_str = format!("{}, {}, {}", current_place.get_name(), current_place.get_year(), clock.time_of_day());
+    print_as_paragraph(ctx, place_name_year_str.as_str(), (frame.width() - 4) as usize, frame.x1 + 2, frame.y1 + 2, PLACE_DATE_DELTA_Y);
+    let generation = ecs.fetch::<MapGeneration>();
+    if generation.enabled {
+        ctx.print_color_text(frame.x1 + 2, frame.y2, PLACE_DATE_SEED_COLOR, BACKGROUND_COLOR, &format!("{} {}", tr(PLACE_DATE_SEED_STR), generation.seed));
+    }
 }
 
 fn draw_tooltips(ecs: &World, ctx: &mut Rltk) {
@@ -171,12 +250,12 @@ fn draw_tooltips(ecs: &World, ctx: &mut Rltk) {
     let mut tooltip: Vec<String> = Vec::new();
     for (name, position, bel, ent) in (&names, &positions, &belongs, &entities).join() {
         if bel.domain == current_place && position.x == mouse_pos.0 && position.y == mouse_pos.1 {
-            let mut name = name.name.to_string();
+            let mut name = tr(&name.name);
             if ecs.read_storage::<Portal>().get(ent).is_some() {
-                name += match ecs.read_storage::<RequiresItem>().get(ent).is_some() {
-                    true => " (Kapali)",
-                    false => " (Acik)"
-                };
+                name += &tr(match ecs.read_storage::<RequiresItem>().get(ent).is_some() {
+                    true => TOOLTIP_LOCKED_STR,
+                    false => TOOLTIP_OPEN_STR
+                });
             }
             tooltip.push(name);
         }
@@ -185,52 +264,57 @@ fn draw_tooltips(ecs: &World, ctx: &mut Rltk) {
     if !tooltip.is_empty() {
         let mut width: i32 = 0;
         for s in tooltip.iter() {
-            if width < s.len() as i32 { width = s.len() as i32; }
+            if width < text_width(s) { width = text_width(s); }
         }
         width += 3;
 
-        if mouse_pos.0 > 40 {
+        if mouse_pos.0 > map.width / 2 {
             let arrow_pos = Point::new(mouse_pos.0 - 2, mouse_pos.1);
             let left_x = mouse_pos.0 - width;
             let mut y = mouse_pos.1;
             for s in tooltip.iter() {
-                ctx.print_color(left_x, y, RGB::named(RED), RGB::named(GREY), s);
-                let padding = (width - s.len() as i32) - 1;
+                ctx.print_color_text(left_x, y, RGB::named(RED), RGB::named(GREY), s);
+                let padding = (width - text_width(s)) - 1;
                 for i in 0..padding {
-                    ctx.print_color(arrow_pos.x - i, y, RGB::named(RED), RGB::named(GREY), &" ".to_string());
+                    ctx.print_color_text(arrow_pos.x - i, y, RGB::named(RED), RGB::named(GREY), " ");
                 }
                 y += 1;
             }
-            ctx.print_color(arrow_pos.x, arrow_pos.y, RGB::named(RED), RGB::named(GREY), &"->".to_string());
+            ctx.print_color_text(arrow_pos.x, arrow_pos.y, RGB::named(RED), RGB::named(GREY), "->");
         } else {
             let arrow_pos = Point::new(mouse_pos.0 + 1, mouse_pos.1);
             let left_x = mouse_pos.0 + 3;
             let mut y = mouse_pos.1;
             for s in tooltip.iter() {
-                ctx.print_color(left_x + 1, y, RGB::named(RED), RGB::named(GREY), s);
-                let padding = (width - s.len() as i32) - 1;
+                ctx.print_color_text(left_x + 1, y, RGB::named(RED), RGB::named(GREY), s);
+                let padding = (width - text_width(s)) - 1;
                 for i in 0..padding {
-                    ctx.print_color(arrow_pos.x + 1 + i, y, RGB::named(RED), RGB::named(GREY), &" ".to_string());
+                    ctx.print_color_text(arrow_pos.x + 1 + i, y, RGB::named(RED), RGB::named(GREY), " ");
                 }
                 y += 1;
             }
-            ctx.print_color(arrow_pos.x, arrow_pos.y, RGB::named(RED), RGB::named(GREY), &"<-".to_string());
+            ctx.print_color_text(arrow_pos.x, arrow_pos.y, RGB::named(RED), RGB::named(GREY), "<-");
         }
     }
 }
 
+/// Top left corner of the first item name in the inventory box
+fn inventory_items_start(frame: &Rect) -> (i32, i32) {
+    (frame.x1 + 2 * INVENTORY_DELTA_Y, frame.y1 + INVENTORY_DELTA_Y)
+}
+
 fn draw_inventory(ecs: &World, ctx: &mut Rltk) {
-    ctx.draw_box(INVENTORY_X, INVENTORY_Y, INVENTORY_WIDTH,
-                 INVENTORY_HEIGHT, INVENTORY_BORDER_COLOR, INVENTORY_BACKGROUND_COLOR);
+    let frame = ecs.fetch::<Layout>().inventory;
+    ctx.draw_box(frame.x1, frame.y1, frame.width(), frame.height(), INVENTORY_BORDER_COLOR, INVENTORY_BACKGROUND_COLOR);
 
     let names = ecs.read_storage::<Name>();
     let backpack = ecs.read_storage::<Stored>();
 
-    let mut y = INVENTORY_ITEMS_Y;
-    ctx.print_color(INVENTORY_BANNER_X, y - 2, RGB::named(YELLOW), BACKGROUND_COLOR, INVENTORY_BANNER);
+    let (items_x, mut y) = inventory_items_start(&frame);
+    ctx.print_color_text_centered_at(frame.center.0, frame.y1, RGB::named(YELLOW), BACKGROUND_COLOR, tr(INVENTORY_BANNER));
 
     for (_pack, name) in (&backpack, &names).join() {
-        ctx.print_color(INVENTORY_ITEMS_X, y, INVENTORY_STRING_COLOR, BACKGROUND_COLOR, &name.name.to_string());
+        ctx.print_color_text(items_x, y, INVENTORY_STRING_COLOR, BACKGROUND_COLOR, tr(&name.name));
         y += INVENTORY_DELTA_Y;
     }
 }
@@ -242,35 +326,63 @@ pub fn draw_use_item(gs: &mut State, ctx: &mut Rltk) -> (ItemMenuResult, Option<
     let inventory = (&backpack, &items).join();
     let count = inventory.count();
 
-    let mut y = INVENTORY_ITEMS_Y;
+    let frame = gs.ecs.fetch::<Layout>().inventory;
+    let (items_x, items_y) = inventory_items_start(&frame);
+    let mut y = items_y;
 
     let mut j = 0;
     let mut usable: Vec<ItemName> = Vec::new();
     for (_pack, item) in (&backpack, &items).join() {
-        ctx.set(INVENTORY_ITEMS_X - 3, y, RGB::named(WHITE), RGB::named(BLACK), rltk::to_cp437('('));
-        ctx.set(INVENTORY_ITEMS_X - 2, y, RGB::named(YELLOW), RGB::named(BLACK), 97 + j as rltk::FontCharType);
-        ctx.set(INVENTORY_ITEMS_X - 1, y, RGB::named(WHITE), RGB::named(BLACK), rltk::to_cp437(')'));
+        ctx.set(items_x - 3, y, RGB::named(WHITE), RGB::named(BLACK), rltk::to_cp437('('));
+        ctx.set(items_x - 2, y, RGB::named(YELLOW), RGB::named(BLACK), 97 + j as rltk::FontCharType);
+        ctx.set(items_x - 1, y, RGB::named(WHITE), RGB::named(BLACK), rltk::to_cp437(')'));
 
         usable.push(item.name);
         y += INVENTORY_DELTA_Y;
         j += 1;
     }
 
+    if gs.ecs.fetch::<KeyBindings>().action(ctx.key) == Some(Action::Cancel) {
+        return (ItemMenuResult::Cancel, None);
+    }
+    if ctx.left_click {
+        let (mouse_x, mouse_y) = ctx.mouse_pos();
+        let inventory_box = rltk::Rect::with_size(frame.x1, frame.y1, frame.width(), frame.height());
+        if !inventory_box.point_in_rect(Point::new(mouse_x, mouse_y)) {
+            return (ItemMenuResult::Cancel, None);
+        }
+        let row = mouse_y - items_y;
+        if row >= 0 && row % INVENTORY_DELTA_Y == 0 && ((row / INVENTORY_DELTA_Y) as usize) < count {
+            return (ItemMenuResult::Selected, Some(usable[(row / INVENTORY_DELTA_Y) as usize]));
+        }
+    }
     if let Some(key) = ctx.key {
         let key_num = rltk::letter_to_option(key);
-        return if key_num > -1 && key_num < count as i32 {
-            (ItemMenuResult::Selected, Some(usable[key_num as usize]))
-        } else {
-            (ItemMenuResult::Cancel, None)
-        };
+        if key_num > -1 && key_num < count as i32 {
+            return (ItemMenuResult::Selected, Some(usable[key_num as usize]));
+        }
     }
     (ItemMenuResult::NoResponse, None)
 }
 
+/// The pages of the dialogue read so far, each next to the glyph of the speaker
+fn draw_dialogue(ctx: &mut Rltk, frame: &Rect, heading: &str, rend: &Renderable, pages: &[String]) {
+    let glyph_x = frame.x1 + NPC_INTERACTION_DIALOGUE_DELTA;
+    let str_x = glyph_x + NPC_INTERACTION_DIALOGUE_DELTA;
+    let heading_y = frame.y1 + NPC_INTERACTION_DIALOGUE_DELTA;
+    let mut y = heading_y + NPC_INTERACTION_DIALOGUE_DELTA;
+    ctx.print_text(frame.center.0 - text_width(heading), heading_y, heading);
+    for dialogue in pages {
+        ctx.set(glyph_x, y, rend.fg, rend.bg, display_glyph(rend.glyph));
+        y = print_as_paragraph(ctx, &tr(dialogue), (frame.width() - 3 * NPC_INTERACTION_DIALOGUE_DELTA) as usize,
+                               str_x, y, NPC_INTERACTION_DIALOGUE_DELTA);
+        y += NPC_INTERACTION_DIALOGUE_DELTA;
+    }
+}
+
 pub fn draw_npc_interaction(ecs: &mut World, ctx: &mut Rltk, dialogue_index: usize) -> NpcInteractionResult {
-    ctx.draw_box(NPC_INTERACTION_SCREEN_X, NPC_INTERACTION_SCREEN_Y,
-                 NPC_INTERACTION_SCREEN_WIDTH, NPC_INTERACTION_SCREEN_HEIGHT,
-                 NPC_INTERACTION_SCREEN_FG, NPC_INTERACTION_SCREEN_BG);
+    let frame = ecs.fetch::<Layout>().dialogue;
+    ctx.draw_box(frame.x1, frame.y1, frame.width(), frame.height(), NPC_INTERACTION_SCREEN_FG, NPC_INTERACTION_SCREEN_BG);
     let positions = ecs.read_storage::<Position>();
     let names = ecs.read_storage::<Name>();
     let renderables = ecs.read_storage::<Renderable>();
@@ -279,45 +391,37 @@ pub fn draw_npc_interaction(ecs: &mut World, ctx: &mut Rltk, dialogue_index: usi
     let current_place = ecs.fetch::<Place>();
     let mut npcs = ecs.write_storage::<Npc>();
     let mut contains_items = ecs.write_storage::<ContainsItems>();
+    let clock = *ecs.fetch::<GameClock>();
     let mut log = ecs.write_resource::<GameLog>();
+    let mut events = ecs.write_resource::<GameEvents>();
     let mut has_interaction = ecs.write_storage::<Interaction>();
     let mut target = ecs.fetch_mut::<TargetedPosition>();
     let mut stored_items = ecs.write_storage::<Stored>();
     let mut requires_items = ecs.write_storage::<RequiresItems>();
     let entities = ecs.entities();
-    for (npc, interaction, pos, name, rend, cont, req, bel) in (&mut npcs, &mut has_interaction, &positions, &names, &renderables, &mut contains_items, &mut requires_items, &belongs).join() {
+    let confirmed = ecs.fetch::<KeyBindings>().action(ctx.key) == Some(Action::Confirm) || ctx.left_click;
+    for (npc, interaction, pos, name, rend, cont, req, bel, npc_entity) in (&mut npcs, &mut has_interaction, &positions, &names, &renderables, &mut contains_items, &mut requires_items, &belongs, &entities).join() {
         if bel.domain == *current_place && pos.x == target.x && pos.y == target.y {
             let mut dialogue_index_returned = dialogue_index + 1;
+            let heading = tr(&name.name);
             match npc.state {
                 NpcState::HasDialogue => {
-                    let glyph_x = NPC_INTERACTION_GLYPH_X;
-                    let str_x = NPC_INTERACTION_DIALOGUE_X;
-                    let mut y = NPC_INTERACTION_DIALOGUE_Y;
-                    let completed_dialogue = &interaction.dialogues[interaction.dialogue_index][0..=dialogue_index];
-                    ctx.print(NPC_INTERACTION_DIALOGUE_HEADING_X - name.name.len() as i32, NPC_INTERACTION_DIALOGUE_HEADING_Y, &name.name);
-                    for dialogue in completed_dialogue {
-                        ctx.set(glyph_x, y, rend.fg, rend.bg, rend.glyph);
-                        y = print_as_paragraph(ctx, &dialogue, NPC_INTERACTION_SCREEN_GAP_WIDTH as usize,
-                                               str_x, y, NPC_INTERACTION_DIALOGUE_DELTA);
-                        y += NPC_INTERACTION_DIALOGUE_DELTA;
-                    }
+                    draw_dialogue(ctx, &frame, &heading, rend, &interaction.dialogues[interaction.dialogue_index][0..=dialogue_index]);
                     if interaction.dialogue_index >= interaction.dialogues.len() - 1 {
                         npc.state = NpcState::Done;
                     }
-                    if let Some(key) = ctx.key {
-                        if key == VirtualKeyCode::Return {
-                            if dialogue_index >= interaction.dialogues[interaction.dialogue_index].len() - 1 {
-                                dialogue_index_returned = 0;
-                                if interaction.give_item_indices.contains(&interaction.dialogue_index) {
-                                    npc.state = NpcState::WillGiveItem;
-                                } else if interaction.get_item_indices.contains(&interaction.dialogue_index) {
-                                    npc.state = NpcState::WantsItem;
-                                } else {
-                                    interaction.dialogue_index += 1;
-                                }
+                    if confirmed {
+                        if dialogue_index >= interaction.dialogues[interaction.dialogue_index].len() - 1 {
+                            dialogue_index_returned = 0;
+                            if interaction.give_item_indices.contains(&interaction.dialogue_index) {
+                                npc.state = NpcState::WillGiveItem;
+                            } else if interaction.get_item_indices.contains(&interaction.dialogue_index) {
+                                npc.state = NpcState::WantsItem;
+                            } else {
+                                interaction.dialogue_index += 1;
                             }
-                            return NpcInteractionResult::NextDialogue { index: dialogue_index_returned };
                         }
+                        return NpcInteractionResult::NextDialogue { index: dialogue_index_returned };
                     }
                 }
                 NpcState::WantsItem => {
@@ -325,10 +429,9 @@ pub fn draw_npc_interaction(ecs: &mut World, ctx: &mut Rltk, dialogue_index: usi
                     for (item, ent) in (&items, &entities).join() {
                         if req.items.get(0).unwrap() == &item.name && stored_items.contains(ent) {
                             let required_item = req.items.remove(0);
-                            let required_item_name = required_item.to_string();
                             interaction.get_item_indices.remove(0);
                             stored_items.remove(ent);
-                            log.entries.push(format!("{} {}", CONSOLE_ITEM_USED, required_item_name));
+                            events.push(GameEvent::ItemUsed(required_item));
                             dont_have_the_item = false;
                             npc.state = NpcState::HasDialogue;
                             interaction.dialogue_index += 1;
@@ -338,28 +441,16 @@ pub fn draw_npc_interaction(ecs: &mut World, ctx: &mut Rltk, dialogue_index: usi
                     }
                     if dont_have_the_item {
                         if interaction.print_no_item {
-                            log.entries.push(format!("{}", CONSOLE_ITEM_NOT_EXIST));
+                            log.push(LogCategory::Item, &clock, tr(CONSOLE_ITEM_NOT_EXIST));
                             interaction.print_no_item = false;
                         }
                         if interaction.repeat {
-                            let glyph_x = NPC_INTERACTION_GLYPH_X;
-                            let str_x = NPC_INTERACTION_DIALOGUE_X;
-                            let mut y = NPC_INTERACTION_DIALOGUE_Y;
-                            let completed_dialogue = &interaction.dialogues[interaction.dialogue_index][0..=dialogue_index];
-                            ctx.print(NPC_INTERACTION_DIALOGUE_HEADING_X - name.name.len() as i32, NPC_INTERACTION_DIALOGUE_HEADING_Y, &name.name);
-                            for dialogue in completed_dialogue {
-                                ctx.set(glyph_x, y, rend.fg, rend.bg, rend.glyph);
-                                y = print_as_paragraph(ctx, &dialogue, NPC_INTERACTION_SCREEN_GAP_WIDTH as usize,
-                                                       str_x, y, NPC_INTERACTION_DIALOGUE_DELTA);
-                                y += NPC_INTERACTION_DIALOGUE_DELTA;
-                            }
-                            if let Some(key) = ctx.key {
-                                if key == VirtualKeyCode::Return {
-                                    target.x = -1;
-                                    target.y = -1;
-                                    interaction.print_no_item = true;
-                                    return NpcInteractionResult::Done;
-                                }
+                            draw_dialogue(ctx, &frame, &heading, rend, &interaction.dialogues[interaction.dialogue_index][0..=dialogue_index]);
+                            if confirmed {
+                                target.x = -1;
+                                target.y = -1;
+                                interaction.print_no_item = true;
+                                return NpcInteractionResult::Done;
                             }
                         } else {
                             target.x = -1;
@@ -373,39 +464,26 @@ pub fn draw_npc_interaction(ecs: &mut World, ctx: &mut Rltk, dialogue_index: usi
                 NpcState::WillGiveItem => {
                     let removed_item_name = cont.items.remove(0);
                     interaction.give_item_indices.remove(0);
-                    let item_name = removed_item_name.to_string();
                     for (item, ent) in (&items, &entities).join() {
                         if item.name == removed_item_name {
                             stored_items.insert(ent, Stored {}).expect("Error during inserting into stored items");
                             break;
                         }
                     }
-                    log.entries.push(format!("{} {}", CONSOLE_ITEM_ACQUIRED, item_name));
+                    events.push(GameEvent::ItemPicked(removed_item_name));
                     npc.state = NpcState::HasDialogue;
                     interaction.dialogue_index += 1;
                 }
                 NpcState::Done => {
-                    let glyph_x = NPC_INTERACTION_GLYPH_X;
-                    let str_x = NPC_INTERACTION_DIALOGUE_X;
-                    let mut y = NPC_INTERACTION_DIALOGUE_Y;
-                    let completed_dialogue = &interaction.dialogues[interaction.dialogue_index][0..=dialogue_index];
-                    ctx.print(NPC_INTERACTION_DIALOGUE_HEADING_X - name.name.len() as i32, NPC_INTERACTION_DIALOGUE_HEADING_Y, &name.name);
-                    for dialogue in completed_dialogue {
-                        ctx.set(glyph_x, y, rend.fg, rend.bg, rend.glyph);
-                        y = print_as_paragraph(ctx, &dialogue, NPC_INTERACTION_SCREEN_GAP_WIDTH as usize,
-                                               str_x, y, NPC_INTERACTION_DIALOGUE_DELTA);
-                        y += NPC_INTERACTION_DIALOGUE_DELTA;
-                    }
-                    if let Some(key) = ctx.key {
-                        if key == VirtualKeyCode::Return {
-                            return NpcInteractionResult::Done;
-                        }
+                    draw_dialogue(ctx, &frame, &heading, rend, &interaction.dialogues[interaction.dialogue_index][0..=dialogue_index]);
+                    if confirmed {
+                        events.push(GameEvent::DialogueFinished(npc_entity));
+                        return NpcInteractionResult::Done;
                     }
                 }
             }
             if interaction.change_objective_indices.contains(&interaction.dialogue_index) {
-                let mut objective = ecs.fetch_mut::<Objective>();
-                objective.index += 1;
+                events.push(GameEvent::ObjectiveAdvanced);
                 interaction.change_objective_indices.remove(0);
             }
             break;
@@ -414,100 +492,564 @@ pub fn draw_npc_interaction(ecs: &mut World, ctx: &mut Rltk, dialogue_index: usi
     NpcInteractionResult::NoResponse
 }
 
-pub fn draw_credits(ctx: &mut Rltk) {
-    ctx.print_color_centered(CREDIT_1_Y, CREDITS_1_COLOR, BACKGROUND_COLOR, CREDIT_1_STR);
-    ctx.print_color_centered(CREDIT_2_Y, CREDITS_2_COLOR, BACKGROUND_COLOR, CREDIT_2_STR);
-    ctx.print_color_centered(CREDIT_3_Y, CREDITS_3_COLOR, BACKGROUND_COLOR, CREDIT_3_STR);
-    ctx.print_color_centered(CREDITS_THANKS_Y, CREDITS_THANKS_COLOR, BACKGROUND_COLOR, CREDITS_THANKS_STR);
-}
-
-fn keycode_to_char(key: Option<VirtualKeyCode>) -> Option<char> {
-    if let Some(keycode) = key {
-        match keycode {
-            VirtualKeyCode::A
-            | VirtualKeyCode::B
-            | VirtualKeyCode::C
-            | VirtualKeyCode::D
-            | VirtualKeyCode::E
-            | VirtualKeyCode::F
-            | VirtualKeyCode::G
-            | VirtualKeyCode::H
-            | VirtualKeyCode::I
-            | VirtualKeyCode::J
-            | VirtualKeyCode::K
-            | VirtualKeyCode::L
-            | VirtualKeyCode::M
-            | VirtualKeyCode::N
-            | VirtualKeyCode::O
-            | VirtualKeyCode::P
-            | VirtualKeyCode::Q
-            | VirtualKeyCode::R
-            | VirtualKeyCode::S
-            | VirtualKeyCode::T
-            | VirtualKeyCode::U
-            | VirtualKeyCode::V
-            | VirtualKeyCode::W
-            | VirtualKeyCode::X
-            | VirtualKeyCode::Y
-            | VirtualKeyCode::Z => {
-                let key_char = (keycode as u8 - VirtualKeyCode::A as u8 + b'A') as char;
-                return Some(key_char);
+pub fn draw_credits(ecs: &World, ctx: &mut Rltk) {
+    let y = ecs.fetch::<Layout>().title_y();
+    ctx.print_color_text_centered(y, CREDITS_1_COLOR, BACKGROUND_COLOR, tr(CREDIT_1_STR));
+    ctx.print_color_text_centered(y + CREDITS_DELTA_Y, CREDITS_2_COLOR, BACKGROUND_COLOR, tr(CREDIT_2_STR));
+    ctx.print_color_text_centered(y + 2 * CREDITS_DELTA_Y, CREDITS_3_COLOR, BACKGROUND_COLOR, tr(CREDIT_3_STR));
+    ctx.print_color_text_centered(y + 2 * CREDITS_DELTA_Y + CREDITS_THANKS_DELTA_Y, CREDITS_THANKS_COLOR, BACKGROUND_COLOR, tr(CREDITS_THANKS_STR));
+}
+
+/// Letters a name can be made of, the Turkish ones come from the keyboard layout
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == ' ' || "çğıöşüÇĞİÖŞÜ".contains(c)
+}
+
+/// The terminal sends no typed characters, so names are spelled from the keys there
+fn keycode_to_char(key: Option<VirtualKeyCode>, shift: bool) -> Option<char> {
+    let keycode = key?;
+    let letter = (keycode as u8).wrapping_sub(VirtualKeyCode::A as u8);
+    if letter < 26 {
+        let key_char = (b'a' + letter) as char;
+        return Some(if shift { key_char.to_ascii_uppercase() } else { key_char });
+    }
+    let digit = (keycode as u8).wrapping_sub(VirtualKeyCode::Key1 as u8);
+    if digit < 10 {
+        return Some(char::from_digit((digit as u32 + 1) % 10, 10).unwrap());
+    }
+    match keycode {
+        VirtualKeyCode::Space => Some(' '),
+        _ => None,
+    }
+}
+
+/// Events of the input queue the game reacts to, the queue is emptied once per tick
+pub struct QueuedInput {
+    pub typed: Vec<char>,
+    /// Latest size reported by the window or the terminal since the previous tick
+    pub resized: Option<Point>,
+}
+
+pub fn queued_input(ctx: &Rltk) -> QueuedInput {
+    let mut input = QueuedInput { typed: Vec::new(), resized: None };
+    INPUT.lock().for_each_message(|event| match event {
+        BEvent::Character { c } => input.typed.push(c),
+        BEvent::Resized { new_size, .. } => input.resized = Some(new_size),
+        _ => {}
+    });
+    if cfg!(feature = "terminal") {
+        input.typed.extend(keycode_to_char(ctx.key, ctx.shift));
+    }
+    input
+}
+
+pub fn draw_enter_name(ecs: &mut World, ctx: &mut Rltk, typed: &[char]) -> bool {
+    let layout = *ecs.fetch::<Layout>();
+    ctx.print_text_centered(layout.menu_y() - 5, tr(ENTER_NAME_STR));
+    let mut player_name = ecs.fetch_mut::<PlayerName>();
+    let letters: Vec<char> = typed.iter().copied().filter(|c| is_name_char(*c)).collect();
+    if letters.is_empty() {
+        match ecs.fetch::<KeyBindings>().action(ctx.key) {
+            Some(Action::Confirm) => {
+                player_name.name = player_name.name.trim_end().to_string();
+                return true;
+            }
+            Some(Action::Reset) => {
+                player_name.name.pop();
             }
             _ => {}
         }
     }
-    None
+    for letter in letters {
+        let starts_word = !player_name.name.is_empty() && !player_name.name.ends_with(' ');
+        if player_name.name.chars().count() < PLAYER_NAME_MAX_LENGTH && (letter != ' ' || starts_word) {
+            player_name.name.push(letter);
+        }
+    }
+    ctx.print_color_text_centered(layout.menu_y(), MENU_SELECTED_COLOR, BACKGROUND_COLOR, &player_name.name);
+    ctx.print_color_text_centered(layout.menu_y() + 2, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR,
+        format!("{}/{}", player_name.name.chars().count(), PLAYER_NAME_MAX_LENGTH));
+    false
 }
 
-pub fn draw_enter_name(ecs: &mut World, ctx: &mut Rltk) -> bool {
-    ctx.print_centered(SCREEN_HEIGHT / 2 - 5, "ISMINIZI GIRIN:");
-    let mut player_name = ecs.fetch_mut::<PlayerName>();
-    match ctx.key {
-        Some(VirtualKeyCode::A)
-        | Some(VirtualKeyCode::B)
-        | Some(VirtualKeyCode::C)
-        | Some(VirtualKeyCode::D)
-        | Some(VirtualKeyCode::E)
-        | Some(VirtualKeyCode::F)
-        | Some(VirtualKeyCode::G)
-        | Some(VirtualKeyCode::H)
-        | Some(VirtualKeyCode::I)
-        | Some(VirtualKeyCode::J)
-        | Some(VirtualKeyCode::K)
-        | Some(VirtualKeyCode::L)
-        | Some(VirtualKeyCode::M)
-        | Some(VirtualKeyCode::N)
-        | Some(VirtualKeyCode::O)
-        | Some(VirtualKeyCode::P)
-        | Some(VirtualKeyCode::Q)
-        | Some(VirtualKeyCode::R)
-        | Some(VirtualKeyCode::S)
-        | Some(VirtualKeyCode::T)
-        | Some(VirtualKeyCode::U)
-        | Some(VirtualKeyCode::V)
-        | Some(VirtualKeyCode::W)
-        | Some(VirtualKeyCode::X)
-        | Some(VirtualKeyCode::Y)
-        | Some(VirtualKeyCode::Z) => {
-            if let Some(char) = keycode_to_char(ctx.key) {
-                player_name.name.push(char);
+#[derive(PartialEq, Copy, Clone)]
+pub enum ProfileMenuResult {
+    NoResponse { selection: usize },
+    /// Index into the profiles
+    Selected { index: usize },
+    New,
+}
+
+/// Lists the profiles with their progress, the entry after them starts a new profile
+pub fn draw_select_profile(ecs: &World, ctx: &mut Rltk, selection: usize) -> ProfileMenuResult {
+    let profiles = ecs.fetch::<Profiles>();
+    let layout = ecs.fetch::<Layout>();
+    ctx.print_color_text_centered(layout.title_y(), RGB::named(RED), BACKGROUND_COLOR, tr(PROFILES_TITLE_STR));
+    let lecturers = lecturers(ecs);
+    let mut entries: Vec<String> = profiles.profiles.iter()
+        .map(|profile| {
+            let lectures = profile.codex.iter().filter(|name| lecturers.contains(name)).count();
+            format!("{} ({} {}, {} {})", profile.name, profile.playthroughs.len(), tr(PROFILES_PLAYTHROUGHS_STR), lectures, tr(PROFILES_LECTURES_STR))
+        })
+        .collect();
+    entries.push(tr(PROFILES_NEW_STR));
+
+    let first_y = layout.title_y() + PROFILES_FIRST_DELTA_Y;
+    let visible = ((layout.height - first_y - 1) / MENU_DELTA_Y).max(1) as usize;
+    let first = (selection + 1).saturating_sub(visible);
+    let center_x = layout.center_x();
+    let mut y = first_y;
+    let mut clicked = None;
+    for (i, entry) in entries.iter().enumerate().skip(first).take(visible) {
+        let (text, color) = menu_entry(ecs, entry, i == selection);
+        ctx.print_color_text_centered(y, color, BACKGROUND_COLOR, &text);
+        if ctx.left_click && mouse_over_centered(ctx, center_x, y, &text) {
+            clicked = Some(i);
+        }
+        y += MENU_DELTA_Y;
+    }
+
+    let chosen = match clicked {
+        Some(index) => Some(index),
+        None => match ecs.fetch::<KeyBindings>().action(ctx.key) {
+            Some(Action::Up) => return ProfileMenuResult::NoResponse { selection: (selection + entries.len() - 1) % entries.len() },
+            Some(Action::Down) => return ProfileMenuResult::NoResponse { selection: (selection + 1) % entries.len() },
+            Some(Action::Confirm) => Some(selection),
+            _ => None,
+        }
+    };
+    match chosen {
+        Some(index) if index < profiles.profiles.len() => ProfileMenuResult::Selected { index },
+        Some(_) => ProfileMenuResult::New,
+        None => ProfileMenuResult::NoResponse { selection },
+    }
+}
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum GameOverSelection {
+    MainMenu,
+    ExportReport,
+    ExportStudySheet,
+}
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum GameOverResult {
+    NoResponse { selected: GameOverSelection },
+    Selected { selected: GameOverSelection },
+}
+
+/// The report card of the won game. `exported` tells which file was exported last and whether
+/// it could be written.
+pub fn draw_game_over(ecs: &World, ctx: &mut Rltk, selected: GameOverSelection, exported: Option<(GameOverSelection, bool)>) -> GameOverResult {
+    let layout = ecs.fetch::<Layout>();
+    let report = Report::new(ecs);
+    let center_x = layout.center_x();
+    ctx.print_color_text_centered(layout.title_y() / 2, RGB::named(RED), BACKGROUND_COLOR, tr(GAME_WON_STR));
+    let mut y = layout.title_y() / 2 + REPORT_FIRST_DELTA_Y;
+    for (label, value) in report.lines() {
+        ctx.print_color_text(center_x - REPORT_LABEL_X_FROM_CENTER, y, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR, &label);
+        ctx.print_color_text(center_x + REPORT_VALUE_X_FROM_CENTER, y, MENU_SELECTED_COLOR, BACKGROUND_COLOR, &value);
+        y += 1;
+    }
+    y += 1;
+    let comment_x = center_x - REPORT_COMMENT_WIDTH as i32 / 2;
+    y = print_as_paragraph(ctx, &report.grade.get_comment(), REPORT_COMMENT_WIDTH, comment_x, y, 1) + MENU_DELTA_Y;
+
+    let options = [
+        (GameOverSelection::MainMenu, tr(REPORT_MAIN_MENU_STR)),
+        (GameOverSelection::ExportReport, tr(REPORT_EXPORT_STR)),
+        (GameOverSelection::ExportStudySheet, tr(STUDY_SHEET_EXPORT_STR)),
+    ];
+    let mut clicked = None;
+    for (option, text) in options.iter() {
+        let (text, color) = menu_entry(ecs, text, *option == selected);
+        ctx.print_color_text_centered(y, color, BACKGROUND_COLOR, &text);
+        if ctx.left_click && mouse_over_centered(ctx, center_x, y, &text) {
+            clicked = Some(*option);
+        }
+        y += MENU_DELTA_Y;
+    }
+    match exported {
+        Some((file, true)) => {
+            let file_name = if file == GameOverSelection::ExportStudySheet { STUDY_SHEET_FILE } else { REPORT_FILE };
+            let path = ecs.fetch::<Settings>().export_path(file_name);
+            ctx.print_color_text_centered(y, RGB::named(YELLOW), BACKGROUND_COLOR, format!("{} {}", tr(EXPORT_SAVED_STR), path.display()));
+        }
+        Some((_, false)) => ctx.print_color_text_centered(y, RGB::named(RED), BACKGROUND_COLOR, tr(EXPORT_FAILED_STR)),
+        None => {}
+    }
+
+    if let Some(option) = clicked {
+        return GameOverResult::Selected { selected: option };
+    }
+    let index = options.iter().position(|(option, _)| *option == selected).unwrap_or(0);
+    match ecs.fetch::<KeyBindings>().action(ctx.key) {
+        Some(Action::Up) => GameOverResult::NoResponse { selected: options[(index + options.len() - 1) % options.len()].0 },
+        Some(Action::Down) => GameOverResult::NoResponse { selected: options[(index + 1) % options.len()].0 },
+        Some(Action::Confirm) => GameOverResult::Selected { selected },
+        _ => GameOverResult::NoResponse { selected },
+    }
+}
+
+
+
+
+pub fn draw_controls(ecs: &mut World, ctx: &mut Rltk, selection: usize, capturing: bool, conflict: Option<Action>) -> ControlsResult {
+    let mut bindings = ecs.fetch_mut::<KeyBindings>();
+    let layout = *ecs.fetch::<Layout>();
+    let hint_y = layout.height - CONTROLS_HINT_FROM_BOTTOM;
+    let title_y = layout.list_title_y(CONTROLS_FIRST_DELTA_Y + Action::ALL.len() as i32 * CONTROLS_DELTA_Y, hint_y);
+    ctx.print_color_text_centered(title_y, RGB::named(RED), BACKGROUND_COLOR, tr(CONTROLS_STR));
+    let (action_x, keys_x) = (layout.center_x() - CONTROLS_ACTION_X_FROM_CENTER, layout.center_x() - CONTROLS_KEYS_X_FROM_CENTER);
+    let mut y = title_y + CONTROLS_FIRST_DELTA_Y;
+    let markers = ecs.fetch::<Settings>().markers;
+    for (i, action) in Action::ALL.iter().enumerate() {
+        let color = match i == selection {
+            true => MENU_SELECTED_COLOR,
+            false => MENU_UNSELECTED_COLOR
+        };
+        if markers && i == selection {
+            ctx.print_color_text(action_x - text_width(MENU_MARKER_LEFT), y, color, BACKGROUND_COLOR, MENU_MARKER_LEFT);
+        }
+        let keys = bindings.keys_of(*action).iter().map(|key| key_name(*key)).collect::<Vec<String>>().join(", ");
+        ctx.print_color_text(action_x, y, color, BACKGROUND_COLOR, action.get_name());
+        ctx.print_color_text(keys_x, y, color, BACKGROUND_COLOR, &keys);
+        y += CONTROLS_DELTA_Y;
+    }
+    let hint = match (capturing, conflict) {
+        (true, _) => tr(CONTROLS_CAPTURE_STR),
+        (false, Some(owner)) => format!("{} {}", tr(CONTROLS_IN_USE_STR), owner.get_name()),
+        (false, None) => tr(CONTROLS_HINT_STR),
+    };
+    ctx.print_color_text_centered(hint_y, RGB::named(YELLOW), BACKGROUND_COLOR, &hint);
+
+    let Some(key) = ctx.key else { return ControlsResult::NoResponse { selection, capturing, conflict }; };
+    let action = Action::ALL[selection];
+    if capturing {
+        if bindings.action(ctx.key) == Some(Action::Cancel) {
+            return ControlsResult::NoResponse { selection, capturing: false, conflict: None };
+        }
+        let conflict = match bindings.bind(action, key) {
+            RebindResult::InUse(owner) => Some(owner),
+            RebindResult::Bound | RebindResult::NotBindable => None,
+        };
+        return ControlsResult::NoResponse { selection, capturing: false, conflict };
+    }
+    match bindings.action(ctx.key) {
+        Some(Action::Up) => ControlsResult::NoResponse { selection: (selection + Action::ALL.len() - 1) % Action::ALL.len(), capturing, conflict: None },
+        Some(Action::Down) => ControlsResult::NoResponse { selection: (selection + 1) % Action::ALL.len(), capturing, conflict: None },
+        Some(Action::Confirm) => ControlsResult::NoResponse { selection, capturing: true, conflict: None },
+        Some(Action::Reset) => {
+            bindings.reset(action);
+            ControlsResult::NoResponse { selection, capturing, conflict: None }
+        }
+        Some(Action::Cancel) => {
+            if bindings.save().is_err() {
+                ecs.write_resource::<GameLog>().push_write_failure(&ecs.fetch::<GameClock>(), KEY_BINDINGS_PATH);
             }
+            ControlsResult::Done
         }
-        Some(VirtualKeyCode::Return) => {
-            return true;
+        _ => ControlsResult::NoResponse { selection, capturing, conflict }
+    }
+}
+
+pub fn draw_pause_menu(ecs: &World, ctx: &mut Rltk, selected: PauseSelection, confirm: Option<PauseConfirm>) -> PauseMenuResult {
+    let bindings = ecs.fetch::<KeyBindings>();
+    let frame = ecs.fetch::<Layout>().pause;
+    ctx.draw_box(frame.x1, frame.y1, frame.width(), frame.height(), INVENTORY_BORDER_COLOR, INVENTORY_BACKGROUND_COLOR);
+    let center_x = frame.center.0;
+    ctx.print_color_text_centered_at(center_x, frame.y1 + 2, RGB::named(YELLOW), BACKGROUND_COLOR, tr(PAUSE_TITLE_STR));
+
+    if let Some(question) = confirm {
+        let text = tr(match question {
+            PauseConfirm::OverwriteSave => CONFIRM_OVERWRITE_STR,
+            PauseConfirm::QuitWithoutSaving => CONFIRM_QUIT_STR,
+        });
+        ctx.print_color_text_centered_at(center_x, frame.center.1, MENU_SELECTED_COLOR, BACKGROUND_COLOR, text);
+        ctx.print_color_text_centered_at(center_x, frame.center.1 + MENU_DELTA_Y, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR, tr(CONFIRM_HINT_STR));
+        let answered = match question {
+            PauseConfirm::OverwriteSave => PauseSelection::Save,
+            PauseConfirm::QuitWithoutSaving => PauseSelection::QuitToMenu,
+        };
+        return match bindings.action(ctx.key) {
+            Some(Action::Confirm) => PauseMenuResult::Selected { selected: answered },
+            Some(Action::Cancel) => PauseMenuResult::NoResponse { selected, confirm: None },
+            _ => PauseMenuResult::NoResponse { selected, confirm },
+        };
+    }
+
+    let save_exists = save_load_system::save_exists();
+    let mut options = vec![(PauseSelection::Resume, tr(PAUSE_RESUME_STR)), (PauseSelection::Save, tr(PAUSE_SAVE_STR))];
+    if save_exists {
+        options.push((PauseSelection::Load, tr(PAUSE_LOAD_STR)));
+    }
+    options.push((PauseSelection::Settings, tr(PAUSE_SETTINGS_STR)));
+    options.push((PauseSelection::Help, tr(PAUSE_HELP_STR)));
+    options.push((PauseSelection::QuitToMenu, tr(PAUSE_QUIT_STR)));
+
+    let mut y = frame.y1 + 5;
+    let mut clicked = None;
+    for (option, text) in options.iter() {
+        let (text, color) = menu_entry(ecs, text, *option == selected);
+        ctx.print_color_text_centered_at(center_x, y, color, BACKGROUND_COLOR, &text);
+        if ctx.left_click && mouse_over_centered(ctx, center_x, y, &text) {
+            clicked = Some(*option);
         }
-        Some(VirtualKeyCode::Delete) => {
-            player_name.name.pop();
+        y += MENU_DELTA_Y;
+    }
+
+    let index = options.iter().position(|(option, _)| Some(*option) == clicked.or(Some(selected))).unwrap_or(0);
+    let action = match clicked {
+        Some(_) => Some(Action::Confirm),
+        None => bindings.action(ctx.key),
+    };
+    match action {
+        Some(Action::Up) => PauseMenuResult::NoResponse { selected: options[(index + options.len() - 1) % options.len()].0, confirm: None },
+        Some(Action::Down) => PauseMenuResult::NoResponse { selected: options[(index + 1) % options.len()].0, confirm: None },
+        Some(Action::Cancel) => PauseMenuResult::Selected { selected: PauseSelection::Resume },
+        Some(Action::Confirm) => {
+            let selected = options[index].0;
+            let unsaved = ecs.fetch::<LastSave>().has_unsaved_progress(ecs);
+            match selected {
+                PauseSelection::Save if save_exists => PauseMenuResult::NoResponse { selected, confirm: Some(PauseConfirm::OverwriteSave) },
+                PauseSelection::QuitToMenu if unsaved => PauseMenuResult::NoResponse { selected, confirm: Some(PauseConfirm::QuitWithoutSaving) },
+                _ => PauseMenuResult::Selected { selected },
+            }
         }
-        _ => {}
+        _ => PauseMenuResult::NoResponse { selected: options[index].0, confirm: None }
     }
-    ctx.print_color_centered_at(SCREEN_WIDTH / 2, SCREEN_HEIGHT / 2, MENU_SELECTED_COLOR, BACKGROUND_COLOR, &player_name.name);
-    false
 }
 
-pub fn draw_game_over(ctx: &mut Rltk) {
-    ctx.print_color_centered(SCREEN_HEIGHT / 2, MENU_SELECTED_COLOR, BACKGROUND_COLOR, "OYUNU KAZANDIN");
+/// Entries of the settings screen, the letter size only exists in the window build
+#[derive(PartialEq, Copy, Clone)]
+enum SettingsEntry {
+    Controls,
+    Transition,
+    Language,
+    Colors,
+    HighContrast,
+    Markers,
+    Hints,
+    LearningRecords,
+    TileSize,
+}
+
+fn settings_entries() -> Vec<SettingsEntry> {
+    let mut entries = vec![SettingsEntry::Controls, SettingsEntry::Transition, SettingsEntry::Language, SettingsEntry::Colors, SettingsEntry::HighContrast, SettingsEntry::Markers, SettingsEntry::Hints, SettingsEntry::LearningRecords];
+    if cfg!(feature = "opengl") {
+        entries.push(SettingsEntry::TileSize);
+    }
+    entries
+}
+
+fn on_off(enabled: bool) -> String {
+    tr(match enabled {
+        true => SETTINGS_ON_STR,
+        false => SETTINGS_OFF_STR
+    })
+}
+
+pub fn draw_settings(ecs: &mut World, ctx: &mut Rltk, selection: usize) -> SettingsResult {
+    let action = ecs.fetch::<KeyBindings>().action(ctx.key);
+    ctx.print_color_text_centered(ecs.fetch::<Layout>().title_y(), RGB::named(RED), BACKGROUND_COLOR, tr(PAUSE_SETTINGS_STR));
+    let entries = settings_entries();
+    {
+        let settings = ecs.fetch::<Settings>();
+        let transition = ecs.fetch::<EraTransition>();
+        let mut y = ecs.fetch::<Layout>().menu_y();
+        for (i, entry) in entries.iter().enumerate() {
+            let text = match entry {
+                SettingsEntry::Controls => tr(CONTROLS_STR),
+                SettingsEntry::Transition => format!("{} {}", tr(SETTINGS_TRANSITION_STR), on_off(transition.enabled)),
+                SettingsEntry::Language => format!("{} {}", tr(SETTINGS_LANGUAGE_STR), language().get_name()),
+                SettingsEntry::Colors => format!("{} {}", tr(SETTINGS_COLORS_STR), settings.color_scheme.get_name()),
+                SettingsEntry::HighContrast => format!("{} {}", tr(SETTINGS_HIGH_CONTRAST_STR), on_off(settings.high_contrast)),
+                SettingsEntry::Markers => format!("{} {}", tr(SETTINGS_MARKERS_STR), on_off(settings.markers)),
+                SettingsEntry::Hints => format!("{} {}", tr(SETTINGS_HINTS_STR), on_off(settings.hints)),
+                SettingsEntry::LearningRecords => format!("{} {}", tr(SETTINGS_LEARNING_RECORDS_STR), on_off(settings.learning_records)),
+                SettingsEntry::TileSize => format!("{} {}px", tr(SETTINGS_TILE_SIZE_STR), settings.tile_pixels),
+            };
+            let (text, color) = menu_entry(ecs, &text, i == selection);
+            ctx.print_color_text_centered(y, color, BACKGROUND_COLOR, &text);
+            y += MENU_DELTA_Y;
+        }
+    }
+    match action {
+        Some(Action::Up) => SettingsResult::NoResponse { selection: (selection + entries.len() - 1) % entries.len() },
+        Some(Action::Down) => SettingsResult::NoResponse { selection: (selection + 1) % entries.len() },
+        Some(Action::Confirm) => {
+            let mut settings = ecs.fetch_mut::<Settings>();
+            match entries[selection] {
+                SettingsEntry::Controls => return SettingsResult::Controls,
+                SettingsEntry::Transition => {
+                    let mut transition = ecs.write_resource::<EraTransition>();
+                    transition.enabled = !transition.enabled;
+                    settings.era_transition = transition.enabled;
+                }
+                SettingsEntry::Language => settings.set_language(language().next()),
+                SettingsEntry::Colors => settings.color_scheme = settings.color_scheme.next(),
+                SettingsEntry::HighContrast => settings.high_contrast = !settings.high_contrast,
+                SettingsEntry::Markers => settings.markers = !settings.markers,
+                SettingsEntry::Hints => settings.hints = !settings.hints,
+                SettingsEntry::LearningRecords => settings.learning_records = !settings.learning_records,
+                SettingsEntry::TileSize => {
+                    let index = TILE_PIXEL_SIZES.iter().position(|size| *size == settings.tile_pixels).unwrap_or(0);
+                    settings.tile_pixels = TILE_PIXEL_SIZES[(index + 1) % TILE_PIXEL_SIZES.len()];
+                    ecs.write_resource::<ScreenSize>().set_tile_pixels(ctx, settings.tile_pixels);
+                }
+            }
+            drop(settings);
+            save_settings(ecs);
+            SettingsResult::NoResponse { selection }
+        }
+        Some(Action::Cancel) => SettingsResult::Done,
+        _ => SettingsResult::NoResponse { selection }
+    }
+}
+
+/// Returns true once the player leaves the screen
+pub fn draw_help(ecs: &World, ctx: &mut Rltk) -> bool {
+    let bindings = ecs.fetch::<KeyBindings>();
+    let layout = ecs.fetch::<Layout>();
+    let width = (HELP_MECHANICS_WIDTH as i32).min(layout.width - 2 * HELP_MARGIN);
+    let x = layout.center_x() - width / 2;
+    let exit_y = layout.height - HELP_EXIT_FROM_BOTTOM;
+    let mechanics: Vec<Vec<String>> = HELP_MECHANICS.iter().map(|mechanic| wrap_text(&tr(mechanic), width as usize)).collect();
+
+    // The title, the two headings, the keys and the mechanics with a blank row between any two
+    // of them, the blank rows go when the screen is too short for them
+    let text_rows = 2 + Action::ALL.len() as i32 + mechanics.iter().map(|lines| lines.len() as i32).sum::<i32>();
+    let gaps = 3 + mechanics.len() as i32;
+    let gap = match text_rows + gaps < exit_y { true => 1, false => 0 };
+    let mut y = layout.list_title_y(text_rows + gap * gaps, exit_y);
+    ctx.print_color_text_centered(y, RGB::named(RED), BACKGROUND_COLOR, tr(PAUSE_HELP_STR));
+    y += 1 + gap;
+    ctx.print_color_text(x, y, RGB::named(YELLOW), BACKGROUND_COLOR, tr(HELP_CONTROLS_STR));
+    y += 1 + gap;
+    for action in Action::ALL.iter() {
+        let keys = bindings.keys_of(*action).iter().map(|key| key_name(*key)).collect::<Vec<String>>().join(", ");
+        ctx.print_color_text(x, y, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR, action.get_name());
+        ctx.print_color_text(x + HELP_KEYS_DELTA_X, y, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR, &keys);
+        y += HELP_DELTA_Y;
+    }
+    y += gap;
+    ctx.print_color_text(x, y, RGB::named(YELLOW), BACKGROUND_COLOR, tr(HELP_MECHANICS_STR));
+    y += 1 + gap;
+    for lines in mechanics.iter() {
+        for line in lines.iter() {
+            ctx.print_text(x, y, line);
+            y += HELP_DELTA_Y;
+        }
+        y += gap;
+    }
+    ctx.print_color_text_centered(exit_y, RGB::named(YELLOW), BACKGROUND_COLOR, tr(HELP_EXIT_STR));
+    ctx.key.is_some()
+}
+
+fn draw_hint(ecs: &World, ctx: &mut Rltk) {
+    let hints = ecs.fetch::<Hints>();
+    if !hints.is_visible() { return; }
+    let Some(hint) = hints.shown else { return; };
+    let frame = ecs.fetch::<Layout>().hint;
+    ctx.draw_box(frame.x1, frame.y1, frame.width(), frame.height(), INVENTORY_BORDER_COLOR, INVENTORY_BACKGROUND_COLOR);
+    ctx.print_color_text(frame.x1 + 2, frame.y1, RGB::named(YELLOW), BACKGROUND_COLOR, tr(HINT_BANNER));
+    print_as_paragraph(ctx, &hint.get_text(), (frame.width() - 4) as usize, frame.x1 + 2, frame.y1 + 2, 1);
+}
+
+fn draw_achievement_toast(ecs: &World, ctx: &mut Rltk) {
+    let mut toasts = ecs.write_resource::<AchievementToasts>();
+    let Some(achievement) = toasts.shown() else { return; };
+    let frame = ecs.fetch::<Layout>().toast;
+    ctx.draw_box(frame.x1, frame.y1, frame.width(), frame.height(), INVENTORY_BORDER_COLOR, INVENTORY_BACKGROUND_COLOR);
+    ctx.print_color_text(frame.x1 + 2, frame.y1, RGB::named(YELLOW), BACKGROUND_COLOR, tr(ACHIEVEMENT_UNLOCKED_STR));
+    ctx.print_color_text(frame.x1 + 2, frame.y1 + 2, INVENTORY_STRING_COLOR, INVENTORY_BACKGROUND_COLOR, achievement.get_name());
+    toasts.advance();
+}
+
+/// Every achievement with its description, the locked ones greyed out. Returns true once the
+/// player leaves the screen
+pub fn draw_achievements(ecs: &World, ctx: &mut Rltk) -> bool {
+    let layout = ecs.fetch::<Layout>();
+    let exit_y = layout.height - HELP_EXIT_FROM_BOTTOM;
+    let title_y = layout.list_title_y(ACHIEVEMENTS_FIRST_DELTA_Y + Achievement::ALL.len() as i32 * ACHIEVEMENTS_DELTA_Y, exit_y);
+    ctx.print_color_text_centered(title_y, RGB::named(RED), BACKGROUND_COLOR, tr(ACHIEVEMENTS_STR));
+    let profiles = ecs.fetch::<Profiles>();
+    let x = layout.center_x() - ACHIEVEMENTS_X_FROM_CENTER;
+    let mut y = title_y + ACHIEVEMENTS_FIRST_DELTA_Y;
+    for achievement in Achievement::ALL.iter() {
+        let unlocked = profiles.active().is_some_and(|profile| profile.achievements.contains(&achievement.id()));
+        let (mark, color) = match unlocked {
+            true => (ACHIEVEMENT_UNLOCKED_MARK, MENU_SELECTED_COLOR),
+            false => (ACHIEVEMENT_LOCKED_MARK, ACHIEVEMENT_LOCKED_COLOR),
+        };
+        ctx.print_color_text(x, y, color, BACKGROUND_COLOR, format!("{} {}", mark, achievement.get_name()));
+        ctx.print_color_text(x + 4, y + 1, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR, achievement.get_description());
+        y += ACHIEVEMENTS_DELTA_Y;
+    }
+    ctx.print_color_text_centered(exit_y, RGB::named(YELLOW), BACKGROUND_COLOR, tr(HELP_EXIT_STR));
+    ctx.key.is_some()
+}
+
+pub enum LogHistoryResult {
+    NoResponse { scroll: usize, filter: Option<LogCategory> },
+    Done,
 }
 
+/// The whole log, newest at the bottom. `scroll` counts the lines hidden below the screen.
+pub fn draw_log_history(ecs: &World, ctx: &mut Rltk, scroll: usize, filter: Option<LogCategory>) -> LogHistoryResult {
+    let log = ecs.fetch::<GameLog>();
+    let layout = ecs.fetch::<Layout>();
+    let history_width = (layout.width - 2 * LOG_HISTORY_X) as usize;
+    let history_lines = (layout.height - LOG_HISTORY_FIRST_Y - LOG_HISTORY_LINES_FROM_BOTTOM).max(1) as usize;
+    ctx.print_color_text_centered(LOG_HISTORY_TITLE_Y, RGB::named(RED), BACKGROUND_COLOR, tr(LOG_HISTORY_TITLE_STR));
+
+    let mut x = LOG_HISTORY_X;
+    ctx.print_color_text(x, LOG_HISTORY_FILTER_Y, RGB::named(YELLOW), BACKGROUND_COLOR, tr(LOG_HISTORY_FILTER_STR));
+    x += text_width(&tr(LOG_HISTORY_FILTER_STR)) + 1;
+    let filters: Vec<Option<LogCategory>> = std::iter::once(None).chain(LogCategory::ALL.iter().copied().map(Some)).collect();
+    for option in filters.iter() {
+        let name = match option {
+            Some(category) => category.get_name(),
+            None => tr(LOG_HISTORY_ALL_STR),
+        };
+        let (text, color) = match *option == filter {
+            true => (format!("[{}]", name), MENU_SELECTED_COLOR),
+            false => (format!(" {} ", name), MENU_UNSELECTED_COLOR),
+        };
+        ctx.print_color_text(x, LOG_HISTORY_FILTER_Y, color, BACKGROUND_COLOR, &text);
+        x += text_width(&text) + 1;
+    }
 
+    // The turn and the time are only written on the first line of an entry
+    let mut lines: Vec<(String, String, RGB)> = Vec::new();
+    for entry in log.filtered(filter) {
+        let stamp = format!("{:>4} {}", entry.turn, entry.time);
+        let width = history_width.saturating_sub(LOG_HISTORY_STAMP_WIDTH as usize);
+        for (i, line) in wrap_text(&entry.text, width).into_iter().enumerate() {
+            let prefix = if i == 0 { stamp.clone() } else { String::new() };
+            lines.push((prefix, line, entry.category.color()));
+        }
+    }
+    if lines.is_empty() {
+        ctx.print_color_text(LOG_HISTORY_X, LOG_HISTORY_FIRST_Y, MENU_UNSELECTED_COLOR, BACKGROUND_COLOR, tr(LOG_HISTORY_EMPTY_STR));
+    }
+    let max_scroll = lines.len().saturating_sub(history_lines);
+    let scroll = scroll.min(max_scroll);
+    let end = lines.len() - scroll;
+    let start = end.saturating_sub(history_lines);
+    for ((stamp, text, color), y) in lines[start..end].iter().zip(LOG_HISTORY_FIRST_Y..) {
+        ctx.print_color_text(LOG_HISTORY_X, y, LOG_TURN_COLOR, BACKGROUND_COLOR, stamp);
+        ctx.print_color_text(LOG_HISTORY_X + LOG_HISTORY_STAMP_WIDTH, y, *color, BACKGROUND_COLOR, text);
+    }
+    ctx.print_color_text_centered(layout.height - LOG_HISTORY_HINT_FROM_BOTTOM, RGB::named(YELLOW), BACKGROUND_COLOR, tr(LOG_HISTORY_HINT_STR));
 
+    let index = filters.iter().position(|option| *option == filter).unwrap_or(0);
+    match ecs.fetch::<KeyBindings>().action(ctx.key) {
+        Some(Action::Up) => LogHistoryResult::NoResponse { scroll: (scroll + 1).min(max_scroll), filter },
+        Some(Action::Down) => LogHistoryResult::NoResponse { scroll: scroll.saturating_sub(1), filter },
+        Some(Action::Left) => LogHistoryResult::NoResponse { scroll: 0, filter: filters[(index + filters.len() - 1) % filters.len()] },
+        Some(Action::Right) => LogHistoryResult::NoResponse { scroll: 0, filter: filters[(index + 1) % filters.len()] },
+        Some(Action::Cancel) | Some(Action::Confirm) | Some(Action::History) => LogHistoryResult::Done,
+        _ => LogHistoryResult::NoResponse { scroll, filter },
+    }
+}
@@ -0,0 +1,63 @@
+use specs::{World, WorldExt};
+use crate::clock::GameClock;
+use crate::constants::{HINT_CRAFTED_STR, HINT_LOCKED_DOOR_STR, HINT_NPC_STR, HINT_TURNS, PROFILES_PATH};
+use crate::gamelog::GameLog;
+use crate::i18n::tr;
+use crate::profiles::Profiles;
+use crate::settings::Settings;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Hint {
+    LockedDoor,
+    Npc,
+    ItemCrafted,
+}
+
+impl Hint {
+    pub fn get_text(&self) -> String {
+        tr(match self {
+            Hint::LockedDoor => HINT_LOCKED_DOOR_STR,
+            Hint::Npc => HINT_NPC_STR,
+            Hint::ItemCrafted => HINT_CRAFTED_STR,
+        })
+    }
+
+    fn id(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// First-time hints, each one is shown once per profile
+#[derive(Default)]
+pub struct Hints {
+    pub shown: Option<Hint>,
+    /// Turns the shown hint stays on the screen
+    pub remaining_turns: u32,
+}
+
+impl Hints {
+    pub fn is_visible(&self) -> bool {
+        self.shown.is_some() && self.remaining_turns > 0
+    }
+
+    pub fn pass_turn(&mut self) {
+        self.remaining_turns = self.remaining_turns.saturating_sub(1);
+    }
+
+    /// Shows the hint unless the active profile has seen it before, the error tells that the
+    /// profiles could not be saved afterwards
+    pub fn show(&mut self, profiles: &mut Profiles, hint: Hint) -> std::io::Result<()> {
+        let Some(profile) = profiles.active_mut() else { return Ok(()); };
+        if !profile.hints_seen.insert(hint.id()) { return Ok(()); }
+        self.shown = Some(hint);
+        self.remaining_turns = HINT_TURNS;
+        profiles.save()
+    }
+}
+
+pub fn show_hint(ecs: &World, hint: Hint) {
+    if !ecs.fetch::<Settings>().hints { return; }
+    if ecs.write_resource::<Hints>().show(&mut ecs.write_resource::<Profiles>(), hint).is_err() {
+        ecs.write_resource::<GameLog>().push_write_failure(&ecs.fetch::<GameClock>(), PROFILES_PATH);
+    }
+}
@@ -0,0 +1,294 @@
+/// English, for exchange students
+pub const STRINGS: &[(&str, &str)] = &[
+    // MENU
+    ("menu.window_title", "History Game"),
+    ("menu.title", "WELCOME TO THE GAME"),
+    ("menu.new_game", "NEW GAME"),
+    ("menu.load_game", "LOAD GAME"),
+    ("menu.quit_game", "QUIT GAME"),
+    ("menu.credits", "CREDITS"),
+    ("menu.controls", "CONTROLS"),
+    ("menu.enter_name", "ENTER YOUR NAME:"),
+    ("menu.game_won", "YOU WON THE GAME"),
+    ("menu.profile", "CHANGE PROFILE"),
+    ("menu.achievements", "ACHIEVEMENTS"),
+    ("profiles.title", "WHO IS PLAYING?"),
+    ("profiles.new", "NEW PROFILE"),
+    ("profiles.playthroughs", "games won"),
+    ("profiles.lectures", "lectures"),
+    ("profiles.current", "Profile:"),
+    ("report.title", "Report card"),
+    ("report.play_time", "Play time:"),
+    ("report.turns", "Turns:"),
+    ("report.places", "Places visited:"),
+    ("report.npcs", "People spoken to:"),
+    ("report.lectures", "Lectures completed:"),
+    ("report.grade", "Taylan Hoca's grade:"),
+    ("report.grade.a", "Taylan Hoca: Excellent! You did not miss a single lesson of the past."),
+    ("report.grade.b", "Taylan Hoca: Very good, only a few stories escaped you."),
+    ("report.grade.c", "Taylan Hoca: Not bad, but the streets still have things to tell you."),
+    ("report.grade.d", "Taylan Hoca: You passed, but go back and listen to the people of the past."),
+    ("report.grade.f", "Taylan Hoca: You found the way out, but history is more than a way out."),
+    ("report.main_menu", "RETURN TO THE MAIN MENU"),
+    ("report.export", "EXPORT THE REPORT"),
+    ("export.saved", "Saved to"),
+    ("export.failed", "The file could not be saved"),
+    ("study_sheet.title", "Study sheet"),
+    ("study_sheet.items", "Items earned"),
+    ("study_sheet.export", "EXPORT THE STUDY SHEET"),
+    // CREDITS
+    ("credits.1", "Aysila Cengiz: For the idea and her support"),
+    ("credits.2", "Herbert Wolverson: For teaching game development with Rust"),
+    ("credits.3", "Sources: Prof. Dr. Halil Incancik: Ottoman History Vol. 9/10/11"),
+    ("credits.thanks", "THANK YOU"),
+    // PAUSE
+    ("pause.title", "PAUSED"),
+    ("pause.resume", "RESUME"),
+    ("pause.save", "SAVE"),
+    ("pause.load", "LOAD"),
+    ("pause.settings", "SETTINGS"),
+    ("pause.help", "HELP"),
+    ("pause.quit", "BACK TO MAIN MENU"),
+    ("pause.confirm_overwrite", "The old save will be deleted."),
+    ("pause.confirm_quit", "Unsaved progress will be lost."),
+    ("pause.confirm_hint", "Confirm: yes, Cancel: no"),
+    // CONSOLE
+    ("console.game_saved", "Game saved."),
+    ("console.item_used", "Item used:"),
+    ("console.item_not_exist", "You don't have the required item"),
+    ("console.item_acquired", "You picked up:"),
+    ("console.item_crafted", "You made a new item:"),
+    ("console.time_travel", "Walking through the secret door, you find yourself in the past!"),
+    ("console.companion_befriended", "follows you now"),
+    ("console.companion_sniff", "is sniffing at the foot of the wall..."),
+    ("console.welcome", "Welcome to the game!"),
+    ("console.wrong_item", "Wrong item"),
+    ("console.dialogue_finished", "finished talking with you"),
+    ("console.new_objective", "New objective:"),
+    ("console.write_failed", "Could not write"),
+    // LOG
+    ("log.title", "MESSAGE LOG"),
+    ("log.filter", "Show:"),
+    ("log.all", "All"),
+    ("log.empty", "Nothing has happened yet."),
+    ("log.hint", "Up/Down: scroll, Left/Right: filter, Cancel: go back"),
+    ("log.category.item", "Items"),
+    ("log.category.dialogue", "Dialogue"),
+    ("log.category.quest", "Quest"),
+    ("log.category.system", "System"),
+    // SETTINGS
+    ("settings.transition", "Time travel effect:"),
+    ("settings.on", "On"),
+    ("settings.off", "Off"),
+    ("settings.language", "Language:"),
+    ("settings.colors", "Colours:"),
+    ("settings.high_contrast", "High contrast:"),
+    ("settings.markers", "Shape markers:"),
+    ("settings.tile_size", "Letter size:"),
+    ("settings.hints", "Hints:"),
+    ("settings.learning_records", "Learning records:"),
+    ("settings.colors.standard", "Standard"),
+    ("settings.colors.protanopia", "Protanopia (red)"),
+    ("settings.colors.deuteranopia", "Deuteranopia (green)"),
+    ("settings.colors.tritanopia", "Tritanopia (blue)"),
+    // HELP
+    ("help.exit", "Press any key to go back"),
+    ("help.controls", "CONTROLS"),
+    ("help.mechanics", "HOW TO PLAY"),
+    ("help.mechanics.0", "Follow your quest in the Quest box on the right, talking to characters unlocks new quests."),
+    ("help.mechanics.1", "Bump into characters to talk to them, advance the dialogue with the Confirm key."),
+    ("help.mechanics.2", "Bumping into a locked door opens the item menu, press the letter of the right item to open the door."),
+    ("help.mechanics.3", "Step on items lying around to put them in your inventory. Items whose parts are all collected combine by themselves."),
+    ("help.mechanics.4", "Click on a tile of the map to walk there, pressing any key stops the walk. Menu entries, items and dialogues can be clicked too."),
+    ("help.mechanics.5", "The animals on campus follow you once you feed them pet food."),
+    // HINT
+    ("hint.banner", "Hint"),
+    ("achievement.animals", "Campus Friend"),
+    ("achievement.animals.description", "Talk to every dog and cat on the campus."),
+    ("achievement.lessons", "Ottoman Scholar"),
+    ("achievement.lessons.description", "Finish all three lessons in the streets of the Ottoman district."),
+    ("achievement.secret_gate", "Pathfinder"),
+    ("achievement.secret_gate.description", "Go through the secret gate in a game played with the hints turned off."),
+    ("achievement.unlocked", "Achievement unlocked:"),
+    ("hint.locked_door", "Bumping into a locked door opens the item menu. Press the letter of the item that opens it, or Cancel to back off."),
+    ("hint.npc", "Bump into characters to talk to them. Press the Confirm key to advance the dialogue."),
+    ("hint.crafted", "Items combine by themselves once all of their parts are in your inventory."),
+    // CONTROLS
+    ("controls.hint", "Confirm: add key, Default: reset keys, Cancel: save and go back"),
+    ("controls.capture", "Press the new key, Cancel to give up"),
+    ("controls.in_use", "This key is the only key of:"),
+    // ACTION
+    ("action.up", "Up"),
+    ("action.down", "Down"),
+    ("action.left", "Left"),
+    ("action.right", "Right"),
+    ("action.up_left", "Up Left"),
+    ("action.up_right", "Up Right"),
+    ("action.down_left", "Down Left"),
+    ("action.down_right", "Down Right"),
+    ("action.confirm", "Confirm"),
+    ("action.cancel", "Cancel"),
+    ("action.reset", "Delete/Default"),
+    ("action.history", "Message log"),
+    // OBJECTIVE
+    ("objective.banner", "Quest"),
+    ("objective.talk_to_taylan", "Go to the classroom and talk to Professor Taylan"),
+    ("objective.find_books", "Find Professor Taylan's books and bring them to class"),
+    ("objective.secret_passage", "Find the secret passage and explore it"),
+    ("objective.lecture_1", "Listen to the first lecture"),
+    ("objective.lecture_2", "Listen to the second lecture"),
+    ("objective.lecture_3", "Listen to the third lecture"),
+    ("objective.lecture_4", "Listen to the fourth lecture"),
+    ("objective.lecture_5", "Listen to the fifth lecture"),
+    ("objective.lecture_6", "Listen to the sixth lecture"),
+    ("objective.lecture_7", "Listen to the seventh lecture"),
+    ("objective.lecture_8", "Listen to the eighth lecture"),
+    ("objective.return_to_stranger", "Talk to the mysterious character again"),
+    ("objective.go_home", "Go back to your own time"),
+    // PLACE
+    ("place.home", "Home"),
+    ("place.school_south", "Bogazici South Campus"),
+    ("place.school_north", "Bogazici North Campus"),
+    ("place.class", "M 2152"),
+    ("place.library", "Not-Bogazici Library"),
+    ("place.ottoman_main", "Istanbul, Square"),
+    ("place.ottoman_left", "Istiklal"),
+    ("place.ottoman_right", "Uskudar"),
+    ("place.ottoman_top", "Sariyer"),
+    ("place.ottoman_bottom", "Eminonu"),
+    // PLACE DATE
+    ("place_date.banner", "Place/Time"),
+    ("place_date.seed", "Seed:"),
+    // INVENTORY
+    ("inventory.banner", "Items"),
+    // ITEM
+    ("item.book", "Professor Taylan's Book"),
+    ("item.secret_gate_key", "Secret Passage Key"),
+    ("item.ottoman_key_1", "West Key"),
+    ("item.poem", "Poem Fragment"),
+    ("item.book_cover", "Book Cover"),
+    ("item.glue", "Glue"),
+    ("item.poem_book", "Poetry Book"),
+    ("item.ottoman_key_2", "North Key"),
+    ("item.mosque_part_1", "Mosque Minaret Model"),
+    ("item.mosque_part_2", "Mosque Body Model"),
+    ("item.mosque_model", "Mosque Model"),
+    ("item.ottoman_key_3", "East Key"),
+    ("item.note_paper", "Sheet Music"),
+    ("item.canvas", "Canvas"),
+    ("item.clay", "Clay"),
+    ("item.weird_collage", "A Weird Collage"),
+    ("item.time_gate_key", "Time Gate Key"),
+    ("item.pet_food", "Pet Food"),
+    // TOOLTIP
+    ("tooltip.locked", " (Locked)"),
+    ("tooltip.open", " (Open)"),
+    // PORTAL
+    ("portal.south_gate", "South Gate"),
+    ("portal.home_gate", "Home Door"),
+    ("portal.class_gate", "M2152 Door"),
+    ("portal.north_gate", "North Gate"),
+    ("portal.library_gate", "Library Door"),
+    ("portal.secret_gate", "Secret Door"),
+    ("portal.west_exit", "West Exit"),
+    ("portal.north_exit", "North Exit"),
+    ("portal.east_exit", "East Exit"),
+    ("portal.south_exit", "South Exit"),
+    ("portal.square_gate", "Square Gate"),
+    ("portal.time_gate", "Time Gate"),
+    // NPC
+    ("npc.taylan.name", "Professor Taylan"),
+    ("npc.taylan.0.0", "Hello there."),
+    ("npc.taylan.0.1", "I left the books I was going to show in class today at the library."),
+    ("npc.taylan.1.0", "Could you bring the books to me, please?"),
+    ("npc.taylan.2.0", "Great, there should be two more."),
+    ("npc.taylan.3.0", "Thank you so much!"),
+    ("npc.taylan.3.1", "Could I have the last one as well?"),
+    ("npc.taylan.4.0", "So you don't have it..."),
+    ("npc.taylan.4.1", "Then I'm giving you this key."),
+    ("npc.taylan.4.2", "If you walk around the South Campus for a while you will find a door this key opens, and my book is behind that door."),
+    ("npc.taylan.5.0", "Have a nice day, don't forget to find the book."),
+    ("npc.mysterious.name", "Mysterious Character"),
+    ("npc.mysterious.0.0", "Greetings, visitor from the future!"),
+    ("npc.mysterious.0.1", "Judging by the look on your face, you are quite surprised."),
+    ("npc.mysterious.0.2", "So let me explain:"),
+    ("npc.mysterious.1.0", "Professor Taylan sends those who make no effort to learn history into the past, to teach them a good lesson."),
+    ("npc.mysterious.1.1", "This time the lucky one seems to be you."),
+    ("npc.mysterious.2.0", "If you want to return to your own time, you have to learn your lesson here, by living it!"),
+    ("npc.mysterious.2.1", "This year's topic is the changes the Ottomans went through in the arts in their last era."),
+    ("npc.mysterious.2.2", "Behind the doors you see are people who will tell you about the topic."),
+    ("npc.mysterious.2.3", "Listen to each of them carefully and come back to me when you are done."),
+    ("npc.mysterious.3.0", "Off you go!"),
+    ("npc.mysterious.3.1", "Start with the first lesson."),
+    ("npc.mysterious.4.0", "So you listened to the first lesson."),
+    ("npc.mysterious.4.1", "Well done!"),
+    ("npc.mysterious.5.0", "Now comes the second lesson."),
+    ("npc.mysterious.6.0", "Listen to the lesson and come back to me!"),
+    ("npc.mysterious.7.0", "So you listened to the second lesson."),
+    ("npc.mysterious.7.1", "Well done!"),
+    ("npc.mysterious.8.0", "Now comes the third lesson."),
+    ("npc.mysterious.9.0", "Listen to the lesson and come back to me!"),
+    ("npc.mysterious.10.0", "So you listened to the third lesson."),
+    ("npc.mysterious.10.1", "Well done!"),
+    ("npc.mysterious.10.2", "You can return to your own time now!"),
+    ("npc.mysterious.11.0", "Take care of yourself."),
+    ("npc.osman.name", "Mr. Osman"),
+    ("npc.osman.0.0", "Civilisations cannot be thought of apart from one another. For this reason, the interaction between civilisations has to be ensured somehow, and this interaction is shared through translation. The first to give translation the value it deserved were the thinkers of the Tanzimat. After Sinasi translated French poems in 1869, only 25 poems were translated until 1880. Fortunately, after 1880 the translation of poems from western countries into our language gained a lot of speed."),
+    ("npc.osman.1.0", "Between 1859 and 1901 at least 802 poems were translated from Western literature into our language, and thanks to this, different forms and subjects began to appear in our poems as well. Perhaps the most important of these new subjects was that the everyday problems of individuals were carried into poetry once again. Among the important poet-translators of the time I can name Muallim Naci, Ahmed Rasim, Recaizade Mahmud Ekrem, Sinasi and Nabizade Nazim; among those known mostly for their translations, Halil Edib, Ahmed Refik and Ali Riza Seyfi."),
+    ("npc.osman.2.0", "I want to share an interesting fact with you: Muallim Naci, who is remembered as an opponent of renewal, is the poet who translated the most in the years I mentioned, with 62 poems. The great majority of the translated poems, 650 of them, came from French literature, followed by English and German literature. The translations being published in newspapers and becoming a part of daily life increased the interest in Western poetry even further. Because of this interest, as I said before, new forms of poetry began to appear."),
+    ("npc.osman.3.0", "This opened the door both to a richness and to a disorder. Although I cannot understand why, fables held an important place in the world of Western poetry, and thanks to the translations they were adopted in our literature too. When looking at which works were translated, one must not forget that the personal preferences of the translators played an important role in these choices. Recaizade Ekrem, for instance, preferred to translate the fables of La Fontaine, while Naci chose the works of Florian."),
+    ("npc.osman.4.0", "I hope what I told you was useful."),
+    ("npc.zeliha.name", "Ms. Zeliha"),
+    ("npc.zeliha.0.0", "Names such as Sinasi, Namik Kemal and Recaizade Mahmut Ekrem, who can be counted among the fixtures of post-Tanzimat literature, did not go through a regular education. Instead they took private lessons. All of them were fluent in French. In the schools that came out of the schooling that began after the Tanzimat, Arabic was not taught. Works of Western literature were seen as more attractive than Arabic and Persian works and were the ones read, so the understanding of literature was shaped accordingly."),
+    ("npc.zeliha.1.0", "In the article he published (Lisan-i Osmaninin Edebiyati Hakkinda Bazi Mulahazati Samildir), Namik Kemal especially emphasised truth and faithfulness to nature, principles of Western rhetoric. From this we can tell that Namik Kemal was influenced by Western works before he went to Europe. Following this, Suleyman Pasa published a work named “Mebani’l Insa”, Ali Cemaleddin one named “Aruz-t Turki” and Mihalicli Mustafa Efendi one named “Zubdetu’l-Beyan”, but none of them could lead the way for the new literature."),
+    ("npc.zeliha.2.0", "Then, in 1879, Recaizade Mahmut Ekrem printed “Talim-i Edebiyat” as a lithograph. In it he says that literature has changed and that this new literature needs to be explained, and he tried to meet that need. Namik Kemal emphasised that writing should be in the spoken language, that both the words and the imagery should be national, and therefore that literature should also be protected from the Western ideas it was approaching. By bringing the questions of nation and homeland to the fore, he put forward a new understanding."),
+    ("npc.zeliha.3.0", "Thank you for listening."),
+    ("npc.zeyneb.name", "Ms. Zeyneb"),
+    ("npc.zeyneb.0.0", "In the times when our state kept losing power against the West, our intellectuals used literature to save the people, even if only a little, from the crisis they were living through. That is why references to social, political and cultural problems are very common in the literature of the last era. Among the writers of the last era, names like Munif Pasa, Ahmet Mithat Efendi and Sadullah Pasa were influenced by the intellectual movements of the time and reflected these ideas in their works. The rationalist movement that reached our lands from the West is one of these movements."),
+    ("npc.zeyneb.1.0", "The translation of Telemak, for example, is a book based directly on Western thought. That Namik Kemal considered it very valuable and that its second edition was translated by Sinasi shows that the writers of the last era were under the influence of rationalism and realism. Another example is how Munif Pasa, in the articles he published one after another in Mecmua-i Funun, drew on Western studies and translated some of them directly."),
+    ("npc.zeyneb.2.0", "Another thing I would like to point out is that the concern of Montesquieu, Voltaire, Jean-Jacques Rousseau and Diderot, who played a great role in the French Enlightenment, to combine reason-based encyclopedic knowledge with their literature can also be seen in most of our writers who produced works between 1839 and 1896."),
+    ("npc.zeyneb.3.0", "Have a nice day."),
+    ("npc.ali.name", "Mr. Ali"),
+    ("npc.ali.0.0", "From the beginning of the 18th century, the Ottomans falling behind the West increased the interest in the West and made the wish to take it as an example inevitable. The Nuruosmaniye Mosque in Istanbul is one of the first examples where Western influence began to show in architecture. If you ask what was different about it: its courtyard was oval, compared to other mosques with a classical form. If we turn to the mosques of Anatolia, the round arches inspired by the West and the new shapes sometimes given to their weight towers are another sign that we were influenced by the West."),
+    ("npc.ali.1.0", "We see that over time Western style motifs were also used on the doors placed in mosques. Another example is the Soke Haci Ziya Bey Mosque, completed in 1895, whose facade almost resembles a European mansion. If we look at tombs, between the 18th and 19th centuries Anatolian tomb architecture steadily lost its importance and artistic value and could not keep up with the fashion of the age. Madrasas, inns and fountains were not influenced by the West or by other movements and did not drift far from their essence."),
+    ("npc.ali.2.0", "Palaces, compared to the buildings we just talked about, contain elements of our own culture along with more elements built on European ideas. Buildings such as inns and bazaars that managed to adapt to daily life continued to exist. Other traditional buildings, since they could not adapt to the changes in society and culture, lost their importance and began to decline in number."),
+    ("npc.ali.3.0", "One important reason for this is that admiration for the West changed points of view and created new needs. The most important buildings that did not lose their importance are mosques, but as I said before, their appearance changed and they took on a Western look. The periods of Western development behind these changes were not applied in a particular order but rather mixed together. In addition, Western style motifs, ornaments and reliefs were used in mosques, and this was a change that also transformed Turkish painting."),
+    ("npc.ali.4.0", "Foreign artists came to Istanbul, produced works and tried to spread what they knew. For many, this was a pioneering event for westernisation in art. If you ask me, Turkish art was already heavily influenced by Europe at that time; they were not pioneers but factors that sped up the change."),
+    ("npc.ali.5.0", "Go in peace."),
+    ("npc.emine.name", "Ms. Emine"),
+    ("npc.emine.0.0", "At the end of the 17th century a style called the Tulip Era was born. The simple patterns in use gave way to more complex ones, and the Baroque style began to be used. After that came the Rococo style, and lighter patterns continued to be used. After Baroque and Rococo came the Empire style. One of the most important examples of the Empire style is the Nusretiye Mosque, built in 1826. In the last era of the Ottomans, the growing interaction with the West, especially with France, affected art in many areas, and architecture is without doubt one of them."),
+    ("npc.emine.1.0", "This period of influence, which began in the 18th century and continued throughout the 19th century, was called the “Westernisation Movement”. Foreign architects from abroad taking part in construction in the Ottoman Empire sped up westernisation. As nationalism gained importance in Europe, admiration and longing for the past grew, and as a result the Neo-Classical style was born. Within this style, techniques based on the past were used in the design of facades."),
+    ("npc.emine.2.0", "For the reasons I just mentioned, similar changes were seen in Ottoman architecture, especially in facades. Foreign architects worked mostly on monumental buildings. Istanbul Sirkeci Station, completed in 1890, is a building where Ottoman and German architecture merge and is considered a part of the transition period. In the light of the Turkism that developed at the end of the 19th and the beginning of the 20th century, the art of architecture moved towards becoming national."),
+    ("npc.emine.3.0", "Because of this, Western methods were left behind. Turkism grew even stronger with the proclamation of the Second Constitutional Era and lasted until the 1930s. Thanks to this understanding, works were produced in a national architectural style different from Western methods. As the times changed, new needs of society emerged, and new buildings began to be built to meet them. Banks, hospitals and museums are examples of these buildings."),
+    ("npc.emine.4.0", "Facades were arranged to resemble Renaissance buildings, with a showy front facade and plainer other facades. Although the exterior changed, there was hardly any change in the interior layout. The reason is the point of view of the time, which saw architecture more as an art than as something serving a functional purpose."),
+    ("npc.emine.5.0", "Farewell."),
+    ("npc.ahmet.name", "Mr. Ahmet"),
+    ("npc.ahmet.0.0", "As the admiration for classical Western music grew, Ottoman music went through a transformation. One of the most important factors behind this were the concerts foreign music masters gave in the Ottoman Empire. The concerts Franz Liszt gave in Istanbul are an example. It may sound interesting, but in those times it was also possible to find borrowings from Turkish music in Europe. The rhythms and melodies Mozart and Beethoven used carry the features of mehter music."),
+    ("npc.ahmet.1.0", "Presenting works as gifts to the sultans was also common, and this closeness with European music increased the interest in Western music and contributed to both musical cultures."),
+    ("npc.ahmet.2.0", "Have a good day."),
+    ("npc.nefise.name", "Ms. Nefise"),
+    ("npc.nefise.0.0", "Towards the end of the 19th century, the first canvas paintings in the Ottoman Empire that could be called Western appeared. As is well known, the Ottoman sultans adopted Western technology to prevent political collapse. Naturally, this led to changes in many areas. Now I will talk about two interesting things you would not expect to be connected. Military schools that took Western education as their model were opened to increase military power. These military schools also had painting classes."),
+    ("npc.nefise.1.0", "At first this class was taught as a technical subject. As a result, Turkish paintings that took the West as their example began to appear. Over time this technical class gained artistic value as well. To take the learning of Western techniques further, groups of students were sent to Europe to learn them on the spot. The first group was sent in 1829, followed by others in 1834, 1835 and 1846. The European countries they went to were England, France and Austria."),
+    ("npc.nefise.2.0", "In later years, students continued to be sent to Europe to receive their art education. Painters who were educated this way and contributed greatly to Turkish painting include Halil Pasa, Sami Yetik and Ibrahim Calli. In 1883 a fine arts academy named Sanayi-i Nefise Mekteb-i Alisi was founded, creating a unit that would carry out painting education as an academic discipline. Teacher-student relationships were formed among the soldier painters. Each generation kept producing works, adding to the legacy left by those before them."),
+    ("npc.nefise.3.0", "Have a nice day."),
+    ("npc.almila.name", "Ms. Almila"),
+    ("npc.almila.0.0", "The making of sculptures reached the Ottoman lands towards the end of the 19th century. The main reason for this was that society did not want to move away from its traditional values. In 1871, on his return from a trip to European cities, Sultan Abdulaziz had a statue of himself made, and this event made sculpture definitely a part of Ottoman art. With the opening of Sanayi-i Nefise Mekteb-i Alisi in 1883, a school to train sculptors opened in the Ottoman Empire for the first time."),
+    ("npc.almila.1.0", "Now, if you allow me, I would like to remember Yervant Osgan Efendi. He is known as the first young Ottoman to study sculpture in Europe. He worked as a teacher at Sanayi-i Nefise for 32 years and played a role in training many artists. He also produced many sculptures himself, to which he added his realistic interpretations."),
+    ("npc.almila.2.0", "See you."),
+    ("npc.hasan.name", "Hasan Efendi"),
+    ("npc.hasan.0.0", "Welcome to the bazaar, my child, this place used to be quieter."),
+    ("npc.efe.name", "Efe"),
+    ("npc.efe.0.0", "Hello."),
+    ("npc.aysila.name", "Aysila"),
+    ("npc.aysila.0.0", "Hi there!"),
+    ("npc.karbeyaz.name", "Karbeyaz"),
+    ("npc.karbeyaz.0.0", "WOOF WOOF"),
+    ("npc.naci.name", "Naci"),
+    ("npc.naci.0.0", "Woof"),
+    ("npc.pasa.name", "Pasa"),
+    ("npc.pasa.0.0", "Woof woof"),
+    ("npc.adolf.name", "Adolf"),
+    ("npc.adolf.0.0", "Mrrrow"),
+    ("npc.deli.name", "Deli"),
+    ("npc.deli.0.0", "Meow"),
+];
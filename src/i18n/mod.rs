@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+mod en;
+mod tr;
+
+/// Languages the interface and the dialogues can be shown in
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Language {
+    Turkish,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Turkish, Language::English];
+
+    /// The name of the language in itself, so it can be found whichever language is active
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Language::Turkish => "Türkçe",
+            Language::English => "English",
+        }
+    }
+
+    /// Used on the command line and in settings files
+    pub fn id(&self) -> &'static str {
+        match self {
+            Language::Turkish => "tr",
+            Language::English => "en",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Language> {
+        Language::ALL.iter().copied().find(|language| language.id() == id)
+    }
+
+    pub fn next(&self) -> Language {
+        let index = Language::ALL.iter().position(|language| language == self).unwrap_or(0);
+        Language::ALL[(index + 1) % Language::ALL.len()]
+    }
+
+    fn strings(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::Turkish => tr::STRINGS,
+            Language::English => en::STRINGS,
+        }
+    }
+
+    fn table(&self) -> &'static HashMap<&'static str, &'static str> {
+        static TABLES: [OnceLock<HashMap<&str, &str>>; 2] = [OnceLock::new(), OnceLock::new()];
+        let index = Language::ALL.iter().position(|language| language == self).unwrap_or(0);
+        TABLES[index].get_or_init(|| self.strings().iter().copied().collect())
+    }
+}
+
+/// Index into `Language::ALL`, a global so that `Display` implementations can translate too
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+pub fn language() -> Language {
+    Language::ALL[ACTIVE.load(Ordering::Relaxed)]
+}
+
+pub fn set_language(language: Language) {
+    let index = Language::ALL.iter().position(|other| *other == language).unwrap_or(0);
+    ACTIVE.store(index, Ordering::Relaxed);
+}
+
+/// Looks the key up in the active language, falling back to Turkish and then to the key itself,
+/// so names typed in by the player pass through unchanged
+pub fn tr(key: &str) -> String {
+    let text = language().table().get(key)
+        .or_else(|| Language::Turkish.table().get(key))
+        .copied()
+        .unwrap_or(key);
+    String::from(text)
+}
@@ -0,0 +1,294 @@
+/// Turkish, the language the game was written in and the fallback of every other table
+pub const STRINGS: &[(&str, &str)] = &[
+    // MENU
+    ("menu.window_title", "Tarih Oyunu"),
+    ("menu.title", "OYUNA HOŞ GELDİN"),
+    ("menu.new_game", "YENİ OYUN"),
+    ("menu.load_game", "OYUN YÜKLE"),
+    ("menu.quit_game", "OYUNDAN ÇIK"),
+    ("menu.credits", "KATKIDA BULUNANLAR"),
+    ("menu.controls", "KONTROLLER"),
+    ("menu.enter_name", "İSMİNİZİ GİRİN:"),
+    ("menu.game_won", "OYUNU KAZANDIN"),
+    ("menu.profile", "PROFİL DEĞİŞTİR"),
+    ("menu.achievements", "BAŞARIMLAR"),
+    ("profiles.title", "KİM OYNUYOR?"),
+    ("profiles.new", "YENİ PROFİL"),
+    ("profiles.playthroughs", "kazanılan oyun"),
+    ("profiles.lectures", "ders"),
+    ("profiles.current", "Profil:"),
+    ("report.title", "Karne"),
+    ("report.play_time", "Oyun süresi:"),
+    ("report.turns", "Tur:"),
+    ("report.places", "Gezilen yerler:"),
+    ("report.npcs", "Konuşulan kişiler:"),
+    ("report.lectures", "Tamamlanan dersler:"),
+    ("report.grade", "Taylan Hoca'nın notu:"),
+    ("report.grade.a", "Taylan Hoca: Mükemmel! Geçmişin hiçbir dersini kaçırmadın."),
+    ("report.grade.b", "Taylan Hoca: Çok iyi, yalnızca birkaç hikâye gözünden kaçtı."),
+    ("report.grade.c", "Taylan Hoca: Fena değil, ama sokakların sana anlatacakları hâlâ var."),
+    ("report.grade.d", "Taylan Hoca: Geçtin, ama dönüp geçmişin insanlarını dinlemelisin."),
+    ("report.grade.f", "Taylan Hoca: Çıkış yolunu buldun, ama tarih bir çıkış yolundan fazlasıdır."),
+    ("report.main_menu", "ANA MENÜYE DÖN"),
+    ("report.export", "RAPORU DIŞA AKTAR"),
+    ("export.saved", "Kaydedildi:"),
+    ("export.failed", "Dosya kaydedilemedi"),
+    ("study_sheet.title", "Çalışma notları"),
+    ("study_sheet.items", "Kazanılan eşyalar"),
+    ("study_sheet.export", "ÇALIŞMA NOTLARINI DIŞA AKTAR"),
+    // CREDITS
+    ("credits.1", "Aysıla Cengiz: Fikri ve desteği için"),
+    ("credits.2", "Herbert Wolverson: Rust ile oyun geliştirmeyi öğrettiği için"),
+    ("credits.3", "Kaynaklar: Prof. Dr. Halil İnalcık: Osmanlı 9./10./11. Cilt"),
+    ("credits.thanks", "TEŞEKKÜRLER"),
+    // PAUSE
+    ("pause.title", "DURAKLATILDI"),
+    ("pause.resume", "DEVAM ET"),
+    ("pause.save", "KAYDET"),
+    ("pause.load", "YÜKLE"),
+    ("pause.settings", "AYARLAR"),
+    ("pause.help", "YARDIM"),
+    ("pause.quit", "ANA MENÜYE DÖN"),
+    ("pause.confirm_overwrite", "Eski kayıt silinecek."),
+    ("pause.confirm_quit", "Kaydedilmemiş ilerleme kaybolacak."),
+    ("pause.confirm_hint", "Onayla: evet, İptal: hayır"),
+    // CONSOLE
+    ("console.game_saved", "Oyun kaydedildi."),
+    ("console.item_used", "Eşya kullanıldı:"),
+    ("console.item_not_exist", "Gerekli eşyan yok"),
+    ("console.item_acquired", "Eşyayı aldın:"),
+    ("console.item_crafted", "Yeni eşya ürettin:"),
+    ("console.time_travel", "Gizli kapıdan geçince kendini geçmişte buldun!"),
+    ("console.companion_befriended", "artık seni takip ediyor"),
+    ("console.companion_sniff", "duvarın dibinde bir şeyler kokluyor..."),
+    ("console.welcome", "Oyuna hoş geldin!"),
+    ("console.wrong_item", "Yanlış eşya"),
+    ("console.dialogue_finished", "ile konuşman bitti"),
+    ("console.new_objective", "Yeni görev:"),
+    ("console.write_failed", "Dosyaya yazılamadı:"),
+    // LOG
+    ("log.title", "GÜNLÜK"),
+    ("log.filter", "Göster:"),
+    ("log.all", "Hepsi"),
+    ("log.empty", "Henüz bir kayıt yok."),
+    ("log.hint", "Yukarı/Aşağı: kaydır, Sol/Sağ: süz, İptal: geri dön"),
+    ("log.category.item", "Eşya"),
+    ("log.category.dialogue", "Diyalog"),
+    ("log.category.quest", "Görev"),
+    ("log.category.system", "Sistem"),
+    // SETTINGS
+    ("settings.transition", "Zaman geçişi efekti:"),
+    ("settings.on", "Açık"),
+    ("settings.off", "Kapalı"),
+    ("settings.language", "Dil:"),
+    ("settings.colors", "Renkler:"),
+    ("settings.high_contrast", "Yüksek karşıtlık:"),
+    ("settings.markers", "Şekil işaretleri:"),
+    ("settings.tile_size", "Harf boyutu:"),
+    ("settings.hints", "İpuçları:"),
+    ("settings.learning_records", "Öğrenme kayıtları:"),
+    ("settings.colors.standard", "Standart"),
+    ("settings.colors.protanopia", "Protanopi (kırmızı)"),
+    ("settings.colors.deuteranopia", "Döteranopi (yeşil)"),
+    ("settings.colors.tritanopia", "Tritanopi (mavi)"),
+    // HELP
+    ("help.exit", "Dönmek için bir tuşa basın"),
+    ("help.controls", "KONTROLLER"),
+    ("help.mechanics", "NASIL OYNANIR"),
+    ("help.mechanics.0", "Görevini sağdaki Görev kutusundan takip et, karakterlerle konuştukça yeni görevler açılır."),
+    ("help.mechanics.1", "Karakterlere çarparak konuşursun, diyaloğu Onayla tuşuyla ilerletirsin."),
+    ("help.mechanics.2", "Kilitli kapılara çarpınca eşya menüsü açılır, doğru eşyanın harfine basarak kapıyı açarsın."),
+    ("help.mechanics.3", "Yerdeki eşyaların üzerine basınca envanterine girerler. Parçaları tamamlanan eşyalar kendiliğinden birleşir."),
+    ("help.mechanics.4", "Haritada bir kareye tıklarsan oraya yürürsün, bir tuşa basmak yürüyüşü durdurur. Menülere, eşyalara ve diyaloglara da tıklayabilirsin."),
+    ("help.mechanics.5", "Kampüsteki hayvanlar mama ile beslenirse seni takip eder."),
+    // HINT
+    ("hint.banner", "İpucu"),
+    ("achievement.animals", "Kampüs Dostu"),
+    ("achievement.animals.description", "Kampüsteki bütün kedi ve köpeklerle konuş."),
+    ("achievement.lessons", "Osmanlı Alimi"),
+    ("achievement.lessons.description", "Osmanlı semtinin sokaklarındaki üç dersin hepsini bitir."),
+    ("achievement.secret_gate", "İz Sürücü"),
+    ("achievement.secret_gate.description", "İpuçları kapalı oynanan bir oyunda gizli kapıdan geç."),
+    ("achievement.unlocked", "Başarım kazanıldı:"),
+    ("hint.locked_door", "Kilitli kapılara çarpınca eşya menüsü açılır. Kapıyı açan eşyanın harfine bas, vazgeçmek için İptal."),
+    ("hint.npc", "Karakterlere çarparak onlarla konuşursun. Diyaloğu ilerletmek için Onayla tuşuna bas."),
+    ("hint.crafted", "Gereken parçaların hepsi envanterine girince eşyalar kendiliğinden birleşir."),
+    // CONTROLS
+    ("controls.hint", "Onayla: tuş ekle, Varsayılan: tuşları sıfırla, İptal: kaydet ve dön"),
+    ("controls.capture", "Yeni tuşa basın, vazgeçmek için İptal"),
+    ("controls.in_use", "Bu tuş şu eylemin tek tuşu:"),
+    // ACTION
+    ("action.up", "Yukarı"),
+    ("action.down", "Aşağı"),
+    ("action.left", "Sol"),
+    ("action.right", "Sağ"),
+    ("action.up_left", "Sol Yukarı"),
+    ("action.up_right", "Sağ Yukarı"),
+    ("action.down_left", "Sol Aşağı"),
+    ("action.down_right", "Sağ Aşağı"),
+    ("action.confirm", "Onayla"),
+    ("action.cancel", "İptal"),
+    ("action.reset", "Sil/Varsayılan"),
+    ("action.history", "Günlük"),
+    // OBJECTIVE
+    ("objective.banner", "Görev"),
+    ("objective.talk_to_taylan", "Sınıfa git ve Taylan Hoca ile konuş"),
+    ("objective.find_books", "Taylan Hoca'nın kitaplarını bul ve derse getir"),
+    ("objective.secret_passage", "Gizli geçidi bul ve araştır"),
+    ("objective.lecture_1", "İlk dersi dinle"),
+    ("objective.lecture_2", "İkinci dersi dinle"),
+    ("objective.lecture_3", "Üçüncü dersi dinle"),
+    ("objective.lecture_4", "Dördüncü dersi dinle"),
+    ("objective.lecture_5", "Beşinci dersi dinle"),
+    ("objective.lecture_6", "Altıncı dersi dinle"),
+    ("objective.lecture_7", "Yedinci dersi dinle"),
+    ("objective.lecture_8", "Sekizinci dersi dinle"),
+    ("objective.return_to_stranger", "Gizemli karakter ile tekrardan konuş"),
+    ("objective.go_home", "Kendi zaman dilimine dön"),
+    // PLACE
+    ("place.home", "Ev"),
+    ("place.school_south", "Boğaziçi Güney Kampüs"),
+    ("place.school_north", "Boğaziçi Kuzey Kampüs"),
+    ("place.class", "M 2152"),
+    ("place.library", "Boğaziçi Olmayan Kütüphane"),
+    ("place.ottoman_main", "İstanbul, Meydan"),
+    ("place.ottoman_left", "İstiklal"),
+    ("place.ottoman_right", "Üsküdar"),
+    ("place.ottoman_top", "Sarıyer"),
+    ("place.ottoman_bottom", "Eminönü"),
+    // PLACE DATE
+    ("place_date.banner", "Mekan/Zaman"),
+    ("place_date.seed", "Tohum:"),
+    // INVENTORY
+    ("inventory.banner", "Eşyalar"),
+    // ITEM
+    ("item.book", "Taylan Hoca'nın Kitabı"),
+    ("item.secret_gate_key", "Gizli Geçit Anahtarı"),
+    ("item.ottoman_key_1", "Batı Anahtarı"),
+    ("item.poem", "Şiir Parçası"),
+    ("item.book_cover", "Kitap Kapağı"),
+    ("item.glue", "Yapıştırıcı"),
+    ("item.poem_book", "Şiir Kitabı"),
+    ("item.ottoman_key_2", "Kuzey Anahtarı"),
+    ("item.mosque_part_1", "Camii Minare Maketi"),
+    ("item.mosque_part_2", "Camii Gövde Maketi"),
+    ("item.mosque_model", "Cami Maketi"),
+    ("item.ottoman_key_3", "Doğu Anahtarı"),
+    ("item.note_paper", "Nota Kağıdı"),
+    ("item.canvas", "Tuval"),
+    ("item.clay", "Kil"),
+    ("item.weird_collage", "Garip Bir Kolaj"),
+    ("item.time_gate_key", "Zaman Kapısı Anahtarı"),
+    ("item.pet_food", "Mama"),
+    // TOOLTIP
+    ("tooltip.locked", " (Kapalı)"),
+    ("tooltip.open", " (Açık)"),
+    // PORTAL
+    ("portal.south_gate", "Güney Kapısı"),
+    ("portal.home_gate", "Ev Kapısı"),
+    ("portal.class_gate", "M2152 Kapısı"),
+    ("portal.north_gate", "Kuzey Kapısı"),
+    ("portal.library_gate", "Kütüphane Kapısı"),
+    ("portal.secret_gate", "Gizli Kapı"),
+    ("portal.west_exit", "Batı Çıkışı"),
+    ("portal.north_exit", "Kuzey Çıkışı"),
+    ("portal.east_exit", "Doğu Çıkışı"),
+    ("portal.south_exit", "Güney Çıkışı"),
+    ("portal.square_gate", "Meydan Kapısı"),
+    ("portal.time_gate", "Zaman Kapısı"),
+    // NPC
+    ("npc.taylan.name", "Taylan Hoca"),
+    ("npc.taylan.0.0", "Merhabalar."),
+    ("npc.taylan.0.1", "Bugün derste göstereceğim kitapları kütüphanede unutmuşum."),
+    ("npc.taylan.1.0", "Rica etsem kitapları getirebilir misiniz?"),
+    ("npc.taylan.2.0", "Süper, iki tane daha olmalı."),
+    ("npc.taylan.3.0", "Çok teşekkürler!"),
+    ("npc.taylan.3.1", "Sonuncuyu da alabilir miyim?"),
+    ("npc.taylan.4.0", "Demek sizde değil..."),
+    ("npc.taylan.4.1", "O zaman size bu anahtarı veriyorum."),
+    ("npc.taylan.4.2", "Güney Kampüs'te biraz gezerseniz bu anahtarın kullanılacağı bir kapı bulacaksınız ve kitabım da o kapının ardında."),
+    ("npc.taylan.5.0", "İyi günler, kitabı bulmayı unutmayın."),
+    ("npc.mysterious.name", "Gizemli Karakter"),
+    ("npc.mysterious.0.0", "Merhabalar gelecekten gelen!"),
+    ("npc.mysterious.0.1", "Yüzündeki ifadeden anladığım kadarıyla oldukça şaşırmış durumdasın."),
+    ("npc.mysterious.0.2", "O yüzden açıklamama izin ver:"),
+    ("npc.mysterious.1.0", "Taylan Hoca, tarihi öğrenmek için bir çaba içerisinde olmayanlara iyi bir ders vermek için onları geçmişe yollar."),
+    ("npc.mysterious.1.1", "Bu sefer de o şanslı kişi sensin belli ki."),
+    ("npc.mysterious.2.0", "Eğer kendi zamanına dönmek istiyorsan dersini burada, yaşayarak öğrenmek zorundasın!"),
+    ("npc.mysterious.2.1", "Bu seneki konu Osmanlı'nın son dönemlerinde sanat alanında yaşadığı değişimler."),
+    ("npc.mysterious.2.2", "Gördüğün kapıların arkasında, konu hakkında seni ilgilendirecek insanlar bulunuyor."),
+    ("npc.mysterious.2.3", "Her birini iyice dinle ve işin bitince bana geri dön."),
+    ("npc.mysterious.3.0", "Hadi bakalım!"),
+    ("npc.mysterious.3.1", "İlk ders ile başla."),
+    ("npc.mysterious.4.0", "Demek ilk dersi dinledin."),
+    ("npc.mysterious.4.1", "Aferin!"),
+    ("npc.mysterious.5.0", "Şimdi sırada ikinci ders var."),
+    ("npc.mysterious.6.0", "Dersi dinle ve bana geri dön!"),
+    ("npc.mysterious.7.0", "Demek ikinci dersi dinledin."),
+    ("npc.mysterious.7.1", "Aferin!"),
+    ("npc.mysterious.8.0", "Şimdi üçüncü ders var."),
+    ("npc.mysterious.9.0", "Dersi dinle ve bana geri dön!"),
+    ("npc.mysterious.10.0", "Demek üçüncü dersi dinledin."),
+    ("npc.mysterious.10.1", "Aferin!"),
+    ("npc.mysterious.10.2", "Artık kendi zamanına dönebilirsin!"),
+    ("npc.mysterious.11.0", "Kendine iyi bak."),
+    ("npc.osman.name", "Osman Bey"),
+    ("npc.osman.0.0", "Medeniyetler, birbirinden ayrı düşünülemezler. Bu sebepten mütevellit, medeniyetler arasındaki etkileşimin bir şekilde sağlanması gerekir. Bu etkileşim de tercüme sayesinde paylaşılır. Tercümeye gerekli değeri ilk verenler Tanzimat düşünürleriydi. Şinasi’nin 1869’da Fransız şiirlerini çevirmesinden sonra 1880’e kadar sadece 25 şiir tercüme edildi. Neyse ki 1880’den sonra batı ülkelerine ait şiirlerin tercüme edilip dilimize aktarılması bir hayli hız kazandı."),
+    ("npc.osman.1.0", "1859-1901 yılları arasında Batı edebiyatından en az 802 şiir dilimize tercüme edildi ve bu sayede bizim şiirlerimizde de farklı formlar ve konular görülmeye başlandı. Bu farklı konulardan belki de en önemlisi, tekrardan bireylerin günlük problemlerinin şiirlere aktarılmasıydı. O dönemin önemli şair-mütercimlerine Muallim Naci, Ahmed Rasim, Recâizâde Mahmud Ekrem, Şinasi ve Nâbizâde Nâzım gibi isimleri; daha çok mütercimlikleri ile bilinen isimlere Halil Edib, Ahmed Refik ve Ali Rıza Seyfi gibi isimleri örnek verebilirim."),
+    ("npc.osman.2.0", "Seninle ilginç bir bilgi paylaşmak istiyorum: Şu yenileşme karşıtı diye anılan Muallim Naci var ya; belirttiğim yıllar arasında 62 manzume ile en fazla tercüme yapan şairimiz odur. Yapılan şiir çevirilerinin büyük bir çoğunluğu 650 şiir ile Fransız edebiyatına aitti ve devamında İngiliz ve Alman edebiyatı geliyordu. Yapılan tercümelerin gazetelerde yayınlanması ve günlük hayatın bir parçası haline gelmesi, Batı şiirine olan ilgiyi daha da arttırmıştır. Bu ilgiden kaynaklı olarak daha önce de belirttiğim gibi yeni şiir formları ortaya çıkmaya başladı."),
+    ("npc.osman.3.0", "Bu durum hem bir zenginliğe hem de düzensizliğe kapı açmış oldu. Neden olduğunu anlayamasam da fabller Batı şiir dünyası içerisinde önemli bir yer kaplamakta idi ve tercümeler sayesinde bizim edebiyatımızda da benimsenmiş oldu. Yapılan tercümelerin hangi eserlere ait olduklarına bakarken, mütercimlerin kişisel tercihlerinin de bu seçimlerde önemli roller oynadığını unutmamak gerekir. Mesela Recâizâde Ekrem daha çok La Fontaine’in fabllarını tercüme etmeyi tercih etmiştir. Naci ise tercihini Florian’ın eserlerinden yana kullanmıştır."),
+    ("npc.osman.4.0", "Umarım anlattıklarım faydalı olmuştur."),
+    ("npc.zeliha.name", "Zeliha Hanım"),
+    ("npc.zeliha.0.0", "Tanzimat sonrası edebiyatının demirbaşları sayılabilecek kişilerinden Şinasi, Namık Kemal, Recâizâde Mahmut Ekrem gibi isimler düzenli bir eğitimden geçmediler. Bunun yerine özel dersler almışlardır. Hepsi Fransız lisanına hakimdiler. Tanzimat’tan sonra başlayan okullaşmanın ürünü olan okullarda Arapça öğretilmemekteydi. Arapça ve Farsça eserler yerine batı edebiyatı eserleri daha cazip görülmekte, bu eserler okutulmakta, dolayısıyla edebiyat anlayışı da buna göre şekillenmekteydi."),
+    ("npc.zeliha.1.0", "Namık Kemal, yayımladığı makalesinde (Lisân-ı Osmânînin Edebiyatı Hakkında Bazı Mülâhazâtı Şâmildir) batı rhétorique’inin prensiplerinden olan hakikat ve tabiata uygunluğu özellikle vurgulamıştır. Namık Kemal’in Avrupa’ya gitmeden Batılı eserlerin etkisinde kaldığı buradan anlaşılabilir. Bunun devamında Süleyman Paşa “Mebâni’l-İnşâ” adında, Ali Cemaleddin “Arûz-ı Türkî” adında, Mihaliçli Mustafa Efendi ise “Zübdetü’l-Beyân” adında eserler yayımlarlar ancak hiçbiri yeni edebiyat için bir öncü görevi göremez."),
+    ("npc.zeliha.2.0", "Derken 1879’da Recâizâde Mahmut Ekrem “Talîm-i Edebiyat”’ı taş baskı halinde çıkarmıştır. Eserinde edebiyatın değiştiğinden, bu yeni edebiyatın açıklanması gerektiğinden bahsetmektedir ve bu ihtiyacı karşılamaya çalışmıştır. Namık Kemal, yazılanların konuşma dilinde olması gerektiğini hem sözlerin hem de hayallerin milli olması gerektiğini, dolayısıyla edebiyatı yaklaşmakta olduğu Batılı fikirlerden de korumak gerektiğini vurgulamıştır. Milliyet ve vatan meselelerini ön plana çıkararak yeni bir anlayış ortaya koymuştur."),
+    ("npc.zeliha.3.0", "Dinlediğin için teşekkür ederim."),
+    ("npc.zeyneb.name", "Zeyneb Hanım"),
+    ("npc.zeyneb.0.0", "Devletimizin Batı karşısında sürekli güç kaybettiği dönemlerde, aydınlarımız edebiyatı halkı yaşadığı bunalımdan biraz da olsa kurtarabilmek adına kullanmışlardır. Bu sebepten dolayı son dönem edebiyatımızda sosyal, siyasal, kültürel problemlere atıflara oldukça rastlanır. Son dönem yazarlarımızdan Münif Paşa, Ahmet Mithat Efendi, Sadullah Paşa gibi isimler dönemin fikir hareketlerinden etkilenerek bu fikirleri eserlerine yansıtmışlardır. Batıdan bizim topraklarımıza ulaşan rasyonalist akım da bu fikir akımlarından biridir."),
+    ("npc.zeyneb.1.0", "Tercüme-i Telemak çevirisi, direkt olarak batı düşüncesine dayanan bir kitaptır mesela. Namık Kemal için çok değerli olarak görülmesi ve Şinasi tarafından ikinci baskısının çevrilmesi, son dönem edebiyatçılarımızın rasyonalizm ve realizmin etkisi altında kaldığını gösterir. Başka bir örnek ise, Münif Paşa’nın Mecmua-i Fünûn’da arka arkaya yayınladığı makaleler içerisinde batıya ait çalışmalardan faydalanılması ve bazı çalışmaların doğrudan çevrilmesidir."),
+    ("npc.zeyneb.2.0", "Belirtmek istediğim başka bir durum ise, Fransız aydınlanmasında büyük rol oynayan Montesquieu, Voltaire, Jean-Jacques Rousseau ve Diderot’nun akla dayalı ansiklopedik bilgiyi edebiyatları ile birleştirme endişelerini; 1839-1896 yılları arasında eser üreten edebiyatçılarımızın çoğunda da görebilmekteyiz."),
+    ("npc.zeyneb.3.0", "İyi günler dilerim."),
+    ("npc.ali.name", "Ali Bey"),
+    ("npc.ali.0.0", "18. yüzyılın başlarından itibaren Osmanlı’nın Batı’nın gerisinde kalması, Batı’ya olan ilgiyi arttırmış ve Batı’yı örnek alma isteğini kaçınılmaz kılmıştı. İstanbul’da bulunan Nuruosmaniye Camisi, Batı etkisinin mimari alanda görülmeye başlandığı ilk örneklerdendir. Ne gibi bir farkı vardı diyecek olursan; avlusu klasik forma sahip diğer camilere nazaran oval bir biçimdeydi. Anadolu camilerine değinecek olursak ise; batıdan esinlenilen yuvarlak kemerler bulundurmaları, ağırlık kulelerine her zaman olmasa da yeni biçimler verilmesi Batı’dan etkilendiğimizin başka bir göstergesidir."),
+    ("npc.ali.1.0", "Camilere yerleştirilen kapıların üzerlerindeki motifler için de zaman içinde batılı tarzda motifler kullanıldığını görüyoruz. Başka bir örnek ise 1895 tarihinde tamamlanan Söke Hacı Ziya Bey Camii’nin cephesi itibarıyla adeta Avrupai bir köşkü andırmasıdır. Türbeler açısından bakacak olursak eğer, 18. ve 19. yüzyıllar arasında Anadolu türbe mimarisi önemini giderek yitirmiş, sanat değerini kaybetmiş ve çağın modasına uyum sağlayamamıştır. Medreseler, hanlar ve çeşmeler, gerek Batı gerek başka fikir akımları tarafından etkilenmeyerek, özlerinden pek kopmamışlardır."),
+    ("npc.ali.2.0", "Saraylar, biraz önce bahsetmiş olduğumuz yapılara nazaran, kendi kültürümüze ait unsurları bulundurmanın yanında, daha fazla Avrupai fikirler ile yapılmış unsurları bünyesinde bulundurmaktadır. Günlük hayata uyum sağlamayı başaran han, çarşı gibi yapılar varlıklarını sürdürmeye devam etmişlerdir. Diğer geleneksel yapılar ise toplumdaki ve kültürdeki değişimlere uyum sağlayamadıklarından mütevellit önemlerini yitirerek sayıca azalmaya başlamışlardır."),
+    ("npc.ali.3.0", "Bunun önemli sebeplerinden biri Batı’ya duyulan özenti sonucunda bakış açılarının değişmesi ve yeni ihtiyaçların ortaya çıkmasıdır. Önemini yitirmeyen en önemli yapılar camilerdir ancak görünüş açısından daha önce de bahsettiğim gibi değişimler geçirmişler, batılı biçimde görünüm kazanmışlardır. Bu değişimlerin sebebi olan Batılı gelişme dönemleri ise belirli bir sıra ile değil, daha çok karma olarak uygulanmıştır. Buna ek olarak, camilerde batılı tarzda motiflere, nakışlara ve kabartmalara yer verilmiştir ve bu Türk resim sanatını da değiştiren bir değişim olmuştur."),
+    ("npc.ali.4.0", "Yabancı sanatçılar İstanbul’a gelerek eser vermişler, kendi bildiklerini yaymaya çalışmışlardır. Birçoğuna göre bu sanat alanındaki batılılaşma için öncü niteliğinde bir olaydır. Bana soracak olursan eğer zaten Türk Sanatı o dönem Avrupa’dan çok etkilenmiş bir noktadaydı, onlar ise öncü değil de değişimi hızlandıran etkenler olmuşlardır."),
+    ("npc.ali.5.0", "Haydi selametle."),
+    ("npc.emine.name", "Emine Hanım"),
+    ("npc.emine.0.0", "17. yüzyılın sonlarında Lale Devri adında bir üslup doğmuştur. Kullanılan sade desenler, yerini daha karmaşık desenlere bırakmıştır ve Barok üslubu kullanılmaya başlanmıştır. Bunun devamında Rokoko üslubuna geçilmiş, daha hafif desenler kullanılmaya devam edilmiştir. Barok ve Rokoko üslubunun devamında ise Ampir üslubuna geçilmiştir. Ampir üslubun en önemli örneklerinden biri ise Nusretiye Camii’dir ve 1826’da yapılmıştır. Osmanlı’nın son dönemlerinde Batı ile, özellikle de Fransa ile, etkileşimlerin artması sanatı birçok alanda etkilemiştir ve mimari de kuşkusuz bu alanlardan biridir."),
+    ("npc.emine.1.0", "18. yüzyılda başlayan ve 19. yüzyıl boyunca devam eden bu etkilenme dönemine “Batılılaşma Hareketi” denmiştir. Yurt dışından gelen yabancı mimarların Osmanlı’da yapı faaliyetlerinin bir parçası olmaları, batılılaşmayı hızlandırmıştır. Avrupa’da ulusçuluk düşüncesinin önem kazanması sonucunda geçmişe duyulan hayranlık ve özlem artmış, sonucunda Neo-Klasik üslup doğmuştur. Bu üslup çerçevesinde cephe düzenlemelerinde geçmişe dayanan teknikler kullanılmıştır."),
+    ("npc.emine.2.0", "Biraz önce bahsetmiş olduğum sebeplerden mütevellit, Osmanlı mimarisinde özellikle cephe yapılarında da benzer değişimler görülmüştür. Yabancı mimarlar özellikle anıtsal yapılar üzerine çalışmışlardır. 1890 yılında tamamlanan İstanbul Sirkeci Garı, Osmanlı ve Alman mimarilerinin kaynaştığı bir yapıdır ve geçiş döneminin bir parçası olarak kabul edilir. 19. yüzyılın sonu ve 20. yüzyılın başlarında gelişen Türkçülük anlayışı ışığında mimarlık sanatı ulusal olma yolunda ilerlemiştir."),
+    ("npc.emine.3.0", "Bunun sebebi olarak batılı yöntemlerden uzaklaşılmıştır. Türkçülük anlayışı, 2. Meşrutiyet’in ilanı ile daha da güçlenmiş ve 1930’lu yıllara kadar varlığını sürdürmüştür. Bu anlayış sayesinde batılı yöntemlerden farklı, milli ve ulusal bir mimari üslup içerisinde eserler verilmiştir. Dönemlerin değişmesi ile birlikte toplumun yeni ihtiyaçları ortaya çıkmıştır ve bu ihtiyaçları karşılamak adına yeni yapılar yapılmaya başlanmıştır. Bu yapılara bankalar, hastaneler, müzeler örnek gösterilebilir."),
+    ("npc.emine.4.0", "Cephe düzenlemeleri Rönesans yapılarına benzeyecek şekilde ön cephenin gösterişli olacağı, diğer cephelerin daha sade kalacağı şekilde ayarlanmıştır. Dış görünüş konusunda değişimler yaşanmasına karşın iç düzenlemeler konusunda pek bir değişim olmamıştır. Bunun sebebi dönemin getirmiş olduğu bir bakış açısı olan, fonksiyonel bir amaç gütmeden, mimariyi daha çok bir sanat olarak görmektir."),
+    ("npc.emine.5.0", "Kalın sağlıcakla."),
+    ("npc.ahmet.name", "Ahmet Bey"),
+    ("npc.ahmet.0.0", "Klasik batı müziğine olan hayranlığın artması sonucu, Osmanlı müziği bir başkalaşım geçirmiştir. Bunun arkasındaki en önemli etkenlerden biri de yabancı müzik üstatlarının Osmanlı’da verdiği konserlerdir. Buna örnek olarak Franz Liszt’in İstanbul’da verdiği konserler verilebilir. Biraz enteresan gelebilir ancak o dönemlerde Avrupa’da da Türk müziğinden alıntılar görmek mümkündür. Mozart ve Beethoven’ın kullanmış oldukları ritimler ve melodiler mehter müziğinin özelliklerini bünyesinde barındırmaktadırlar."),
+    ("npc.ahmet.1.0", "Padişahlara eserler hediye edilmesi de sıkça gözlenen bir durumdur ve Avrupa müziği ile kurulan bu yakınlık, batı müziğine olan ilgiyi arttırmış ve iki müzik kültürüne de katkılarda bulunmuştur."),
+    ("npc.ahmet.2.0", "Hayırlı günler."),
+    ("npc.nefise.name", "Nefise Hanım"),
+    ("npc.nefise.0.0", "19. yüzyıl sonlarına doğru Osmanlı’da batılı sayılabilecek ilk tuval resimleri ortaya çıkmıştır. Bilindiği üzere Osmanlı padişahları siyasi çöküşün önüne geçebilmek adına batılı teknolojiyi benimsemişlerdir. Doğal olarak bu benimseyiş birçok alanda değişimlere yol açmıştır. Şimdi enteresan ve arasında bağlantı olması beklenmeyen iki durumdan bahsedeceğim. Askeri gücü arttırabilmek adına batılı eğitimi öncü kabul eden askeri okullar açıldı. Açılan bu askeri okullarda da resim dersi mevcuttu."),
+    ("npc.nefise.1.0", "Bu ders ilk başlarda teknik bir ders olarak verilmekteydi. Bunun sonucunda da batıyı örnek alan Türk resimleri ortaya çıkmaya başladı. Zaman içinde teknik olan bu ders sanatsal bir değer de kazanmaya başladı. Batıdaki tekniklerin öğrenimini daha ileri bir seviyeye taşımak amacıyla, bu teknikleri yerinde öğrenmeleri için Avrupa’ya öğrenci grupları gönderilmiştir. İlk grup 1829’da gönderilmiş, devamı 1834, 1835, 1846 şeklinde devam etmiştir. Gidilen Avrupa ülkeleri ise İngiltere, Fransa ve Avusturya olmuştur."),
+    ("npc.nefise.2.0", "İleriki senelerde, sanat eğitimini Avrupa’da almaları için de öğrenci gönderimi devam etmiştir. Bu şekilde eğitim gören ve Türk resmine büyük katkıları bulunan ressamlara Halil Paşa, Sami Yetik, İbrahim Çallı örnek verilebilir. 1883 yılında Sanayi-i Nefise Mekteb-i Âlisi adında bir güzel sanatlar akademisi kurulmuş ve resim eğitimini akademik bir disiplin ile yürütecek bir birim oluşturulmuştur. Asker ressamlar arasında öğretmen-öğrenci ilişkileri kurulmuştur. Her kuşak kendilerinden önce gelenlerin bıraktıkları mirasa eklemeler yaparak eser vermeye devam etmişlerdir."),
+    ("npc.nefise.3.0", "İyi günler dilerim."),
+    ("npc.almila.name", "Almila Hanım"),
+    ("npc.almila.0.0", "Heykel üretimi Osmanlı topraklarına 19. yüzyılın sonlarına doğru varmıştır. Bunun en büyük sebebi, toplumun geleneksel değerlerinden uzaklaşmak istememesidir. 1871 yılında, Sultan Abdülaziz Avrupa kentlerine yapmış olduğu gezi dönüşünde kendi heykelini yaptırmıştır ve bu olay heykel sanatının kesin olarak Osmanlı sanatının bir parçası haline gelmesini sağlamıştır. 1883 yılında Sanayi-i Nefise Mekteb-i Âlisi’nin açılmasının ardından Osmanlı’da heykeltıraş yetiştirecek bir okul ilk kez açılmış olur."),
+    ("npc.almila.1.0", "Şimdi izninle Yervant Osgan Efendi’yi yad etmek istiyorum. Kendisi Avrupa’da heykel üzerine öğrenim gören ilk Osmanlı genci olması ile bilinir. Sanayi-i Nefise’de 32 yıl boyunca öğretim üyesi olarak çalışır, birçok sanatçının yetişmesinde rol oynar. Aynı zamanda kendisi de gerçekçi yorumlarını kattığı bir sürü heykel üretmiştir."),
+    ("npc.almila.2.0", "Görüşmek üzere."),
+    ("npc.hasan.name", "Hasan Efendi"),
+    ("npc.hasan.0.0", "Çarşıya hoş geldin evladım, buralar eskiden daha sakindi."),
+    ("npc.efe.name", "Efe"),
+    ("npc.efe.0.0", "Merhaba."),
+    ("npc.aysila.name", "Aysila"),
+    ("npc.aysila.0.0", "Selamlar!"),
+    ("npc.karbeyaz.name", "Karbeyaz"),
+    ("npc.karbeyaz.0.0", "HAV HAV"),
+    ("npc.naci.name", "Naci"),
+    ("npc.naci.0.0", "Hav"),
+    ("npc.pasa.name", "Pasa"),
+    ("npc.pasa.0.0", "Hav Hav"),
+    ("npc.adolf.name", "Adolf"),
+    ("npc.adolf.0.0", "Mrrnav"),
+    ("npc.deli.name", "Deli"),
+    ("npc.deli.0.0", "Miyav"),
+];
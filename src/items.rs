@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display, Formatter};
 use serde::{Deserialize, Serialize};
-use crate::constants::{ITEM_BOOK_NAME, ITEM_SECRET_GATE_KEY_NAME, OTTOMAN_KEY_1_NAME, OTTOMAN_KEY_2_NAME, OTTOMAN_KEY_3_NAME, OTTOMAN_KEY_MAIN_NAME, OTTOMAN_REWARD_POEM_NAME, OTTOMAN_REWARD_BOOK_COVER_NAME, OTTOMAN_COMBINED_REWARD_POEM_BOOK_NAME, OTTOMAN_REWARD_GLUE_NAME, OTTOMAN_REWARD_MOSQUE_PART_1_NAME, OTTOMAN_REWARD_MOSQUE_PART_2_NAME, OTTOMAN_COMBINED_REWARD_MOSQUE_MODEL_NAME, OTTOMAN_REWARD_NOTE_PAPER_NAME, OTTOMAN_REWARD_CANVAS_NAME, OTTOMAN_REWARD_CLAY_NAME, OTTOMAN_COMBINED_REWARD_WEIRD_COLLAGE_NAME};
+use crate::constants::{ITEM_BOOK_NAME, ITEM_SECRET_GATE_KEY_NAME, OTTOMAN_KEY_1_NAME, OTTOMAN_KEY_2_NAME, OTTOMAN_KEY_3_NAME, OTTOMAN_KEY_MAIN_NAME, OTTOMAN_REWARD_POEM_NAME, OTTOMAN_REWARD_BOOK_COVER_NAME, OTTOMAN_COMBINED_REWARD_POEM_BOOK_NAME, OTTOMAN_REWARD_GLUE_NAME, OTTOMAN_REWARD_MOSQUE_PART_1_NAME, OTTOMAN_REWARD_MOSQUE_PART_2_NAME, OTTOMAN_COMBINED_REWARD_MOSQUE_MODEL_NAME, OTTOMAN_REWARD_NOTE_PAPER_NAME, OTTOMAN_REWARD_CANVAS_NAME, OTTOMAN_REWARD_CLAY_NAME, OTTOMAN_COMBINED_REWARD_WEIRD_COLLAGE_NAME, ITEM_PET_FOOD_NAME};
+use crate::i18n::tr;
 
 #[derive(PartialEq, Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum ItemName {
@@ -21,11 +22,13 @@ pub enum ItemName {
     OttomanRewardClay,
     OttomanCombinedRewardWeirdCollage,
     OttomanKeyMain,
+    PetFood,
 }
 
-impl Display for ItemName {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let str = match self {
+impl ItemName {
+    /// The key of the item's name in the string tables
+    pub fn key(&self) -> &'static str {
+        match self {
             ItemName::Book => ITEM_BOOK_NAME,
             ItemName::SecretGateKey => ITEM_SECRET_GATE_KEY_NAME,
             ItemName::OttomanKey1 => OTTOMAN_KEY_1_NAME,
@@ -43,7 +46,13 @@ impl Display for ItemName {
             ItemName::OttomanRewardClay => OTTOMAN_REWARD_CLAY_NAME,
             ItemName::OttomanKeyMain => OTTOMAN_KEY_MAIN_NAME,
             ItemName::OttomanCombinedRewardWeirdCollage => OTTOMAN_COMBINED_REWARD_WEIRD_COLLAGE_NAME,
-        }.to_string();
-        write!(f, "{}", str)
+            ItemName::PetFood => ITEM_PET_FOOD_NAME,
+        }
+    }
+}
+
+impl Display for ItemName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", tr(self.key()))
     }
-}
\ No newline at end of file
+}
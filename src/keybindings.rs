@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::fs;
+use rltk::VirtualKeyCode;
+use crate::constants::{ACTION_CANCEL_STR, ACTION_CONFIRM_STR, ACTION_DOWN_LEFT_STR, ACTION_DOWN_RIGHT_STR, ACTION_DOWN_STR, ACTION_HISTORY_STR, ACTION_LEFT_STR, ACTION_RESET_STR, ACTION_RIGHT_STR, ACTION_UP_LEFT_STR, ACTION_UP_RIGHT_STR, ACTION_UP_STR, KEY_BINDINGS_PATH};
+use crate::i18n::tr;
+
+/// Everything a key can be bound to, the game and the menus only ever look at actions
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+    Confirm,
+    Cancel,
+    /// Deletes a letter while typing, restores the defaults of an action on the controls screen
+    Reset,
+    /// Opens the full game log
+    History,
+}
+
+impl Action {
+    pub const ALL: [Action; 12] = [Action::Up, Action::Down, Action::Left, Action::Right, Action::UpLeft, Action::UpRight,
+        Action::DownLeft, Action::DownRight, Action::Confirm, Action::Cancel, Action::Reset, Action::History];
+
+    pub fn get_name(&self) -> String {
+        tr(match self {
+            Action::Up => ACTION_UP_STR,
+            Action::Down => ACTION_DOWN_STR,
+            Action::Left => ACTION_LEFT_STR,
+            Action::Right => ACTION_RIGHT_STR,
+            Action::UpLeft => ACTION_UP_LEFT_STR,
+            Action::UpRight => ACTION_UP_RIGHT_STR,
+            Action::DownLeft => ACTION_DOWN_LEFT_STR,
+            Action::DownRight => ACTION_DOWN_RIGHT_STR,
+            Action::Confirm => ACTION_CONFIRM_STR,
+            Action::Cancel => ACTION_CANCEL_STR,
+            Action::Reset => ACTION_RESET_STR,
+            Action::History => ACTION_HISTORY_STR,
+        })
+    }
+
+    /// Used in the bindings file
+    fn id(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    pub fn direction(&self) -> Option<(i32, i32)> {
+        match self {
+            Action::Up => Some((0, -1)),
+            Action::Down => Some((0, 1)),
+            Action::Left => Some((-1, 0)),
+            Action::Right => Some((1, 0)),
+            Action::UpLeft => Some((-1, -1)),
+            Action::UpRight => Some((1, -1)),
+            Action::DownLeft => Some((-1, 1)),
+            Action::DownRight => Some((1, 1)),
+            _ => None,
+        }
+    }
+
+    /// Arrow keys, WASD, vi-keys and the numpad
+    fn default_keys(&self) -> Vec<VirtualKeyCode> {
+        use VirtualKeyCode::*;
+        match self {
+            Action::Up => vec![Up, W, K, Numpad8],
+            Action::Down => vec![Down, S, J, Numpad2],
+            Action::Left => vec![Left, A, H, Numpad4],
+            Action::Right => vec![Right, D, L, Numpad6],
+            Action::UpLeft => vec![Y, Numpad7],
+            Action::UpRight => vec![U, Numpad9],
+            Action::DownLeft => vec![B, Numpad1],
+            Action::DownRight => vec![N, Numpad3],
+            Action::Confirm => vec![Return, NumpadEnter],
+            Action::Cancel => vec![Escape],
+            Action::Reset => vec![Back, Delete],
+            Action::History => vec![M, Tab],
+        }
+    }
+}
+
+/// Keys that can be written in the bindings file
+const BINDABLE_KEYS: &[VirtualKeyCode] = {
+    use VirtualKeyCode::*;
+    &[A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+        Key0, Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9,
+        Numpad0, Numpad1, Numpad2, Numpad3, Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9, NumpadEnter,
+        Up, Down, Left, Right, Home, End, PageUp, PageDown, Insert, Delete, Back, Return, Escape, Space, Tab,
+        F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12]
+};
+
+pub fn key_name(key: VirtualKeyCode) -> String {
+    format!("{:?}", key)
+}
+
+fn key_from_name(name: &str) -> Option<VirtualKeyCode> {
+    BINDABLE_KEYS.iter().copied().find(|key| key_name(*key) == name)
+}
+
+pub enum RebindResult {
+    Bound,
+    /// The key is the last one left for the given action
+    InUse(Action),
+    NotBindable,
+}
+
+pub struct KeyBindings {
+    keys: HashMap<Action, Vec<VirtualKeyCode>>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings { keys: Action::ALL.iter().map(|action| (*action, action.default_keys())).collect() }
+    }
+}
+
+impl KeyBindings {
+    /// Reads the bindings file, actions missing from it keep their default keys
+    pub fn load() -> Self {
+        let mut bindings = KeyBindings::default();
+        let Ok(contents) = fs::read_to_string(KEY_BINDINGS_PATH) else { return bindings; };
+        let Ok(file) = serde_json::from_str::<HashMap<String, Vec<String>>>(&contents) else { return bindings; };
+        for action in Action::ALL {
+            if let Some(names) = file.get(&action.id()) {
+                let keys: Vec<VirtualKeyCode> = names.iter().filter_map(|name| key_from_name(name)).collect();
+                if !keys.is_empty() {
+                    bindings.keys.insert(action, keys);
+                }
+            }
+        }
+        bindings
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let file: HashMap<String, Vec<String>> = self.keys.iter()
+            .map(|(action, keys)| (action.id(), keys.iter().map(|key| key_name(*key)).collect()))
+            .collect();
+        let contents = serde_json::to_string_pretty(&file)?;
+        fs::write(KEY_BINDINGS_PATH, contents)
+    }
+
+    pub fn action(&self, key: Option<VirtualKeyCode>) -> Option<Action> {
+        let key = key?;
+        Action::ALL.iter().copied().find(|action| self.keys_of(*action).contains(&key))
+    }
+
+    pub fn keys_of(&self, action: Action) -> &[VirtualKeyCode] {
+        self.keys.get(&action).map(|keys| keys.as_slice()).unwrap_or(&[])
+    }
+
+    /// Adds the key to the action, taking it away from the action it was bound to
+    pub fn bind(&mut self, action: Action, key: VirtualKeyCode) -> RebindResult {
+        if !BINDABLE_KEYS.contains(&key) {
+            return RebindResult::NotBindable;
+        }
+        if let Some(owner) = self.action(Some(key)) {
+            if owner == action {
+                return RebindResult::Bound;
+            }
+            if self.keys_of(owner).len() == 1 {
+                return RebindResult::InUse(owner);
+            }
+            self.keys.get_mut(&owner).unwrap().retain(|bound| *bound != key);
+        }
+        self.keys.entry(action).or_default().push(key);
+        RebindResult::Bound
+    }
+
+    /// Gives the action its default keys back, unless another action has taken one of them since
+    pub fn reset(&mut self, action: Action) {
+        let taken: Vec<VirtualKeyCode> = self.keys.iter()
+            .filter(|(other, _)| **other != action)
+            .flat_map(|(_, keys)| keys.iter().copied())
+            .collect();
+        let keys: Vec<VirtualKeyCode> = action.default_keys().into_iter().filter(|key| !taken.contains(key)).collect();
+        if !keys.is_empty() {
+            self.keys.insert(action, keys);
+        }
+    }
+}
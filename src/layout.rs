@@ -0,0 +1,125 @@
+use rltk::{Point, Rltk};
+use crate::constants::{ACHIEVEMENT_TOAST_HEIGHT, ACHIEVEMENT_TOAST_WIDTH, HINT_BOX_HEIGHT, HINT_BOX_X, HINT_BOX_Y, INVENTORY_HEIGHT, INVENTORY_OVERLAY_WIDTH, MAP_HEIGHT, MAP_WIDTH, MIN_SCREEN_HEIGHT, MIN_SCREEN_WIDTH, NPC_INTERACTION_SCREEN_HEIGHT, NPC_INTERACTION_SCREEN_WIDTH, OBJECTIVE_BOX_Y, PAUSE_BOX_HEIGHT, PAUSE_BOX_WIDTH, PLACE_DATE_BOX_HEIGHT, SCREEN_HEIGHT, SCREEN_WIDTH, SIDE_PANEL_MAX_WIDTH, SIDE_PANEL_MIN_WIDTH, TILE_PIXELS};
+use crate::rect::Rect;
+
+/// Size of the console in letters, follows the window or the terminal the game runs in
+pub struct ScreenSize {
+    pub width: i32,
+    pub height: i32,
+    /// Size of a letter in the window, the terminal build ignores it
+    pub tile_pixels: i32,
+}
+
+impl ScreenSize {
+    pub fn new(tile_pixels: i32) -> Self {
+        ScreenSize { width: SCREEN_WIDTH, height: SCREEN_HEIGHT, tile_pixels }
+    }
+
+    /// The window reports its new size in pixels and the console grows or shrinks to fill it
+    /// with letters of the chosen size, never below what the map needs
+    #[cfg(feature = "opengl")]
+    pub fn resize(&mut self, ctx: &mut Rltk, new_size: Point) {
+        self.width = (new_size.x / self.tile_pixels).max(MIN_SCREEN_WIDTH);
+        self.height = (new_size.y / self.tile_pixels).max(MIN_SCREEN_HEIGHT);
+        ctx.set_char_size(self.width as u32, self.height as u32);
+    }
+
+    /// Fits the console to the window again with letters of the new size
+    pub fn set_tile_pixels(&mut self, ctx: &mut Rltk, tile_pixels: i32) {
+        self.tile_pixels = tile_pixels;
+        if cfg!(feature = "opengl") {
+            self.resize(ctx, Point::new(ctx.width_pixels, ctx.height_pixels));
+        }
+    }
+
+    /// The terminal reports its size in letters. The console of the terminal build keeps the
+    /// size it started with, so the layout shrinks to the part the terminal shows.
+    #[cfg(not(feature = "opengl"))]
+    pub fn resize(&mut self, ctx: &mut Rltk, new_size: Point) {
+        let (console_width, console_height) = ctx.get_char_size();
+        self.width = new_size.x.min(console_width as i32).max(MIN_SCREEN_WIDTH);
+        self.height = new_size.y.min(console_height as i32).max(MIN_SCREEN_HEIGHT);
+    }
+}
+
+/// The boxes on the right of the map, left out when the screen is too narrow for them
+#[derive(Clone, Copy)]
+pub struct SidePanel {
+    pub objective: Rect,
+    pub place_date: Rect,
+}
+
+/// Where every panel goes on a screen of the current size. The map always starts at the top
+/// left corner, so map and mouse coordinates stay the same.
+#[derive(Clone, Copy)]
+pub struct Layout {
+    pub width: i32,
+    pub height: i32,
+    pub side_panel: Option<SidePanel>,
+    /// In the side panel, or opened over the map while choosing an item when there is none
+    pub inventory: Rect,
+    pub log: Rect,
+    pub dialogue: Rect,
+    pub hint: Rect,
+    pub pause: Rect,
+    /// Announces an unlocked achievement at the bottom of the map
+    pub toast: Rect,
+}
+
+impl Layout {
+    pub fn new(size: &ScreenSize) -> Layout {
+        let (width, height) = (size.width, size.height);
+        let map_width = width.min(MAP_WIDTH);
+        let panel_width = (width - MAP_WIDTH - 1).min(SIDE_PANEL_MAX_WIDTH);
+
+        let (side_panel, inventory) = if panel_width >= SIDE_PANEL_MIN_WIDTH {
+            let inventory = Rect::new(MAP_WIDTH, MAP_HEIGHT - INVENTORY_HEIGHT - 1, panel_width, INVENTORY_HEIGHT);
+            let place_date = Rect::new(MAP_WIDTH, inventory.y1 - PLACE_DATE_BOX_HEIGHT - 1, panel_width, PLACE_DATE_BOX_HEIGHT);
+            let objective = Rect::new(MAP_WIDTH, OBJECTIVE_BOX_Y, panel_width, place_date.y1 - OBJECTIVE_BOX_Y - 1);
+            (Some(SidePanel { objective, place_date }), inventory)
+        } else {
+            let inventory_width = INVENTORY_OVERLAY_WIDTH.min(width - 2);
+            (None, Rect::new((map_width - inventory_width) / 2, (MAP_HEIGHT - INVENTORY_HEIGHT) / 2, inventory_width, INVENTORY_HEIGHT))
+        };
+
+        let dialogue_width = NPC_INTERACTION_SCREEN_WIDTH.min(width - 2);
+        let dialogue_height = NPC_INTERACTION_SCREEN_HEIGHT.min(height - 1);
+        let toast_width = ACHIEVEMENT_TOAST_WIDTH.min(width - 2);
+        Layout {
+            width,
+            height,
+            side_panel,
+            inventory,
+            log: Rect::new(0, MAP_HEIGHT, width - 1, height - MAP_HEIGHT - 1),
+            dialogue: Rect::new((map_width - dialogue_width) / 2, 0, dialogue_width, dialogue_height),
+            hint: Rect::new(HINT_BOX_X, HINT_BOX_Y, map_width - 3, HINT_BOX_HEIGHT),
+            pause: Rect::new((width - PAUSE_BOX_WIDTH) / 2, (height - PAUSE_BOX_HEIGHT) / 2, PAUSE_BOX_WIDTH, PAUSE_BOX_HEIGHT),
+            toast: Rect::new((map_width - toast_width) / 2, MAP_HEIGHT - ACHIEVEMENT_TOAST_HEIGHT - 2, toast_width, ACHIEVEMENT_TOAST_HEIGHT),
+        }
+    }
+
+    pub fn center_x(&self) -> i32 {
+        self.width / 2
+    }
+
+    pub fn title_y(&self) -> i32 {
+        self.height / 3
+    }
+
+    /// Title of a screen with a long list under it, half as far down as the menu titles unless
+    /// the `rows` below it would then reach `bottom_y`
+    pub fn list_title_y(&self, rows: i32, bottom_y: i32) -> i32 {
+        (self.title_y() / 2).min(bottom_y - rows - 1).max(0)
+    }
+
+    /// First entry of the menus in the middle of the screen
+    pub fn menu_y(&self) -> i32 {
+        self.height / 2
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout::new(&ScreenSize::new(TILE_PIXELS))
+    }
+}
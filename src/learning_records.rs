@@ -0,0 +1,123 @@
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::Serialize;
+use crate::constants::LEARNING_RECORD_TIMEOUT_SECONDS;
+use crate::settings::Settings;
+
+/// What the actor did, named after the xAPI verbs
+#[derive(Serialize, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum Verb {
+    Experienced,
+    Completed,
+}
+
+#[derive(Serialize, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectKind {
+    Lecture,
+    Place,
+}
+
+#[derive(Serialize)]
+pub struct Actor {
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub struct RecordObject {
+    pub kind: ObjectKind,
+    /// Stays the same whatever the language
+    pub id: String,
+    /// In the language the game is played in
+    pub name: String,
+}
+
+/// One line of the learning records, a simplified xAPI statement
+#[derive(Serialize)]
+pub struct Statement {
+    pub actor: Actor,
+    pub verb: Verb,
+    pub object: RecordObject,
+    /// UTC, in the ISO 8601 form xAPI uses
+    pub timestamp: String,
+}
+
+impl Statement {
+    pub fn new(actor: &str, verb: Verb, kind: ObjectKind, id: &str, name: String) -> Self {
+        Statement {
+            actor: Actor { name: actor.to_string() },
+            verb,
+            object: RecordObject { kind, id: id.to_string(), name },
+            timestamp: utc_timestamp(),
+        }
+    }
+}
+
+/// Days since the Unix epoch to the civil date, after Howard Hinnant's algorithm
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = (if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn utc_timestamp() -> String {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs() as i64).unwrap_or(0);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let time = seconds.rem_euclid(86_400);
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, time / 3600, time / 60 % 60, time % 60)
+}
+
+/// Appends the statement to the records file and posts it to the endpoint, when the settings
+/// ask for learning records at all; the endpoint is sent back through `failed_posts` when the
+/// post does not go through
+pub fn record(settings: &Settings, statement: &Statement, failed_posts: &Sender<String>) -> io::Result<()> {
+    if !settings.learning_records { return Ok(()); }
+    let line = serde_json::to_string(statement)?;
+    if !settings.learning_records_endpoint.is_empty() {
+        let (endpoint, body) = (settings.learning_records_endpoint.clone(), line.clone());
+        let failed_posts = failed_posts.clone();
+        // A slow or missing receiver must not hold up the game
+        thread::spawn(move || {
+            if post(&endpoint, &body).is_err() {
+                let _ = failed_posts.send(endpoint);
+            }
+        });
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(&settings.learning_records_file)?;
+    writeln!(file, "{}", line)
+}
+
+/// Splits `http://host:port/path` into the address to connect to, the host and the path
+fn parse_endpoint(endpoint: &str) -> Option<(String, String, String)> {
+    let rest = endpoint.strip_prefix("http://")?;
+    let (host, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+    let address = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+    Some((address, host.to_string(), path.to_string()))
+}
+
+/// Plain HTTP is enough for a receiver on the same machine or the school network
+fn post(endpoint: &str, body: &str) -> io::Result<()> {
+    let (address, host, path) = parse_endpoint(endpoint).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, endpoint.to_string()))?;
+    let socket = address.to_socket_addrs()?.next().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, address.clone()))?;
+    let timeout = Duration::from_secs(LEARNING_RECORD_TIMEOUT_SECONDS);
+    let mut stream = TcpStream::connect_timeout(&socket, timeout)?;
+    stream.set_write_timeout(Some(timeout))?;
+    let request = format!("POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                          path, host, body.len(), body);
+    stream.write_all(request.as_bytes())
+}
@@ -1,9 +1,10 @@
-use rltk::{GameState, Rltk, Point, RGB, BLACK};
+use rltk::{GameState, Rltk, Point, RGB};
 use specs::prelude::*;
 
 mod player;
 mod components;
 mod map;
+mod places;
 mod rect;
 mod gui;
 mod gamelog;
@@ -13,48 +14,101 @@ mod constants;
 mod items;
 mod systems;
 mod npcs;
+mod triggers;
+mod events;
+mod dispatcher;
+mod clock;
+mod palette;
+mod glyphs;
+mod keybindings;
+mod hints;
+mod i18n;
+mod layout;
+mod settings;
+mod profiles;
+mod achievements;
+mod report;
+mod study_sheet;
+mod learning_records;
 
 use player::*;
 pub use components::*;
 pub use map::*;
+pub use places::*;
 use rect::*;
-use crate::gamelog::GameLog;
-use crate::gui::{ItemMenuResult, MainMenuResult, MainMenuSelection, NpcInteractionResult};
+use crate::events::{GameEvent, GameEvents};
+use crate::gamelog::{GameLog, LogCategory};
+use crate::gui::{ControlsResult, GameOverResult, GameOverSelection, ItemMenuResult, LogHistoryResult, MainMenuResult, MainMenuSelection, NpcInteractionResult, PauseConfirm, PauseMenuResult, PauseSelection, ProfileMenuResult, SettingsResult};
 use specs::saveload::{SimpleMarker, SimpleMarkerAllocator};
-use crate::constants::{HOME_FROM_SCHOOL_COORD, HOME_TO_SCHOOL_PORTAL_COORD, SCHOOL_SOUTH_FROM_HOME_COORD, SCHOOL_SOUTH_FROM_CLASS_COORD, SCHOOL_TO_HOME_PORTAL_COORD, SCHOOL_SOUTH_TO_CLASS_PORTAL_COORD, CLASS_TO_SCHOOL_PORTAL_COORD, CLASS_FROM_SCHOOL_COORD, SCHOOL_SOUTH_TO_SCHOOL_NORTH_PORTAL_COORD, SCHOOL_SOUTH_FROM_SCHOOL_NORTH_COORD, SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD, OTTOMAN_FROM_SCHOOL_COORD, SCHOOL_SOUTH_FROM_OTTOMAN_COORD, OTTOMAN_TO_LEFT_PORTAL_COORD, OTTOMAN_FROM_LEFT_COORD, OTTOMAN_LEFT_FROM_MAIN_COORD, OTTOMAN_LEFT_TO_MAIN_PORTAL_COORD, OTTOMAN_TO_TOP_PORTAL_COORD, OTTOMAN_FROM_TOP_COORD, OTTOMAN_TOP_FROM_MAIN_COORD, OTTOMAN_TOP_TO_MAIN_PORTAL_COORD, OTTOMAN_TO_SCHOOL_PORTAL_COORD, OTTOMAN_TO_RIGHT_PORTAL_COORD, OTTOMAN_FROM_RIGHT_COORD, OTTOMAN_RIGHT_FROM_MAIN_COORD, OTTOMAN_RIGHT_TO_MAIN_PORTAL_COORD, OTTOMAN_TO_BOTTOM_PORTAL_COORD, OTTOMAN_BOTTOM_FROM_MAIN_COORD, OTTOMAN_BOTTOM_TO_MAIN_PORTAL_COORD, OTTOMAN_FROM_BOTTOM_COORD, CLASS_WIDTH, CLASS_X, CLASS_Y, CLASS_HEIGHT, OTTOMAN_MAIN_X, OTTOMAN_MAIN_WIDTH, OTTOMAN_MAIN_Y, OTTOMAN_MAIN_HEIGHT, OTTOMAN_LEFT_WIDTH, OTTOMAN_LEFT_X, OTTOMAN_LEFT_HEIGHT, OTTOMAN_LEFT_Y, OTTOMAN_TOP_X, OTTOMAN_TOP_WIDTH, OTTOMAN_TOP_Y, OTTOMAN_TOP_HEIGHT, OTTOMAN_RIGHT_X, OTTOMAN_RIGHT_WIDTH, OTTOMAN_RIGHT_Y, OTTOMAN_RIGHT_HEIGHT, OTTOMAN_BOTTOM_X, OTTOMAN_BOTTOM_WIDTH, OTTOMAN_BOTTOM_Y, OTTOMAN_BOTTOM_HEIGHT, SCHOOL_NORTH_FROM_SCHOOL_SOUTH_COORD, SCHOOL_NORTH_TO_SCHOOL_SOUTH_PORTAL_COORD, SCHOOL_NORTH_TO_LIBRARY_PORTAL_COORD, LIBRARY_FROM_SCHOOL_NORTH_COORD, LIBRARY_TO_SCHOOL_NORTH_PORTAL_COORD, SCHOOL_NORTH_FROM_LIBRARY_COORD};
+use crate::clock::GameClock;
+use crate::constants::{FONT_FILE, WINDOW_TITLE_STR, FONT_TILE_SIZE, LOCKED_DOOR_CHAR, SCREEN_HEIGHT, SCREEN_WIDTH, CLOCK_MINUTES_PER_DIALOGUE_PAGE, CONSOLE_GAME_SAVED, CONSOLE_WELCOME, CONSOLE_WRONG_ITEM, CONSOLE_TIME_TRAVEL, HOME_FROM_SCHOOL_COORD, HOME_TO_SCHOOL_PORTAL_COORD, SCHOOL_SOUTH_FROM_HOME_COORD, SCHOOL_SOUTH_FROM_CLASS_COORD, SCHOOL_TO_HOME_PORTAL_COORD, SCHOOL_SOUTH_TO_CLASS_PORTAL_COORD, CLASS_TO_SCHOOL_PORTAL_COORD, CLASS_FROM_SCHOOL_COORD, SCHOOL_SOUTH_TO_SCHOOL_NORTH_PORTAL_COORD, SCHOOL_SOUTH_FROM_SCHOOL_NORTH_COORD, SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD, OTTOMAN_FROM_SCHOOL_COORD, SCHOOL_SOUTH_FROM_OTTOMAN_COORD, OTTOMAN_TO_LEFT_PORTAL_COORD, OTTOMAN_FROM_LEFT_COORD, OTTOMAN_LEFT_FROM_MAIN_COORD, OTTOMAN_LEFT_TO_MAIN_PORTAL_COORD, OTTOMAN_TO_TOP_PORTAL_COORD, OTTOMAN_FROM_TOP_COORD, OTTOMAN_TOP_FROM_MAIN_COORD, OTTOMAN_TOP_TO_MAIN_PORTAL_COORD, OTTOMAN_TO_SCHOOL_PORTAL_COORD, OTTOMAN_TO_RIGHT_PORTAL_COORD, OTTOMAN_FROM_RIGHT_COORD, OTTOMAN_RIGHT_FROM_MAIN_COORD, OTTOMAN_RIGHT_TO_MAIN_PORTAL_COORD, OTTOMAN_TO_BOTTOM_PORTAL_COORD, OTTOMAN_BOTTOM_FROM_MAIN_COORD, OTTOMAN_BOTTOM_TO_MAIN_PORTAL_COORD, OTTOMAN_FROM_BOTTOM_COORD, CLASS_WIDTH, CLASS_X, CLASS_Y, CLASS_HEIGHT, OTTOMAN_MAIN_X, OTTOMAN_MAIN_WIDTH, OTTOMAN_MAIN_Y, OTTOMAN_MAIN_HEIGHT, OTTOMAN_LEFT_WIDTH, OTTOMAN_LEFT_X, OTTOMAN_LEFT_HEIGHT, OTTOMAN_LEFT_Y, OTTOMAN_TOP_X, OTTOMAN_TOP_WIDTH, OTTOMAN_TOP_Y, OTTOMAN_TOP_HEIGHT, OTTOMAN_RIGHT_X, OTTOMAN_RIGHT_WIDTH, OTTOMAN_RIGHT_Y, OTTOMAN_RIGHT_HEIGHT, OTTOMAN_BOTTOM_X, OTTOMAN_BOTTOM_WIDTH, OTTOMAN_BOTTOM_Y, OTTOMAN_BOTTOM_HEIGHT, SCHOOL_NORTH_FROM_SCHOOL_SOUTH_COORD, SCHOOL_NORTH_TO_SCHOOL_SOUTH_PORTAL_COORD, SCHOOL_NORTH_TO_LIBRARY_PORTAL_COORD, LIBRARY_FROM_SCHOOL_NORTH_COORD, LIBRARY_TO_SCHOOL_NORTH_PORTAL_COORD, SCHOOL_NORTH_FROM_LIBRARY_COORD};
 use crate::items::ItemName;
+use crate::hints::Hint;
+use crate::i18n::{Language, tr};
+use crate::keybindings::{Action, KeyBindings};
+use crate::layout::{Layout, ScreenSize};
+use crate::profiles::Profiles;
+use crate::report::{GameStatistics, Report};
+use crate::settings::Settings;
+use crate::triggers::{TriggerArea, TriggerEffect};
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum RunState {
     Menu { menu_selection: MainMenuSelection },
+    /// `from_settings` is set when the screen was opened from the settings, `in_game` when
+    /// those were opened from the pause menu
+    Controls { selection: usize, capturing: bool, conflict: Option<Action>, from_settings: bool, in_game: bool },
+    Paused { selected: PauseSelection, confirm: Option<PauseConfirm> },
+    /// `in_game` is set when the screen was opened from the pause menu
+    Settings { selection: usize, in_game: bool },
+    Help,
+    LogHistory { scroll: usize, filter: Option<LogCategory> },
+    SelectProfile { selection: usize },
+    Achievements,
+    /// Typing the name of a new profile
     EnterName,
     Game,
     SaveGame,
     UseInventory,
     InteractNpc { index: usize },
     Credits,
-    GameOver,
+    /// `exported` tells which file was exported last and whether it could be written
+    GameOver { selected: GameOverSelection, exported: Option<(GameOverSelection, bool)> },
 }
 
 pub struct State {
     ecs: World,
+    dispatcher: Dispatcher<'static, 'static>,
+    event_dispatcher: Dispatcher<'static, 'static>,
 }
 
 impl State {
-    fn run_systems(&mut self) {
-        let mut item_collection_system = systems::ItemCollectionSystem {};
-        item_collection_system.run_now(&self.ecs);
-
-        let mut item_adjustment_system = systems::ItemAdjustmentSystem {};
-        item_adjustment_system.run_now(&self.ecs);
+    /// Names the player after the active profile and starts playing, a profile is made first
+    /// when there is none
+    fn start_new_game(&mut self) -> RunState {
+        let Some(profile_name) = self.ecs.fetch::<Profiles>().active().map(|profile| profile.name.clone()) else {
+            self.ecs.write_resource::<PlayerName>().name.clear();
+            return RunState::EnterName;
+        };
+        let mut names = self.ecs.write_storage::<Name>();
+        let players = self.ecs.read_storage::<Player>();
+        for (name, _player) in (&mut names, &players).join() {
+            name.name = profile_name.clone();
+        }
+        self.ecs.write_resource::<PlayerName>().name = profile_name;
+        RunState::Game
+    }
 
-        let mut door_reveal_system = systems::DoorRevealSystem {};
-        door_reveal_system.run_now(&self.ecs);
+    fn run_systems(&mut self) {
+        self.dispatcher.dispatch(&self.ecs);
+        self.ecs.maintain();
+    }
 
-        let mut item_combination_system = systems::ItemCombinationSystem {};
-        item_combination_system.run_now(&self.ecs);
+    /// Lets the consumers react to the events written during the tick, then clears them
+    fn run_event_consumers(&mut self) {
+        self.event_dispatcher.dispatch(&self.ecs);
 
-        self.ecs.maintain();
+        self.ecs.write_resource::<GameEvents>().events.clear();
     }
 }
 
@@ -65,6 +119,13 @@ impl GameState for State {
             let state_reader = self.ecs.fetch::<RunState>();
             run_state = *state_reader;
         }
+        let previous_run_state = run_state;
+        let input = gui::queued_input(ctx);
+        if let Some(new_size) = input.resized {
+            self.ecs.write_resource::<ScreenSize>().resize(ctx, new_size);
+        }
+        let layout = Layout::new(&self.ecs.fetch::<ScreenSize>());
+        self.ecs.insert(layout);
         ctx.cls();
 
         {
@@ -72,15 +133,18 @@ impl GameState for State {
             let stored = self.ecs.read_storage::<Stored>();
             let current_place = self.ecs.read_resource::<Place>();
             for (item, _) in (&items, &stored).join() {
-                if *current_place == Place::SchoolSouth && item.name == ItemName::OttomanKeyMain {
-                    run_state = RunState::GameOver;
+                if *current_place == Place::SCHOOL_SOUTH && item.name == ItemName::OttomanKeyMain && !matches!(run_state, RunState::GameOver { .. }) {
+                    run_state = RunState::GameOver { selected: GameOverSelection::MainMenu, exported: None };
                 }
             }
         }
 
         match run_state {
-            RunState::Menu { .. } | RunState::Credits | RunState::EnterName | RunState::GameOver => {}
+            RunState::Menu { .. } | RunState::Controls { .. } | RunState::Settings { .. } | RunState::Help | RunState::LogHistory { .. } | RunState::SelectProfile { .. } | RunState::Achievements | RunState::Credits | RunState::EnterName | RunState::GameOver { .. } => {}
             _ => {
+                if !matches!(run_state, RunState::Paused { .. }) {
+                    self.ecs.write_resource::<GameStatistics>().played_seconds += ctx.frame_time_ms as f64 / 1000.0;
+                }
                 {
                     let current_place = *self.ecs.fetch::<Place>();
                     let map_place = self.ecs.fetch::<Map>().place;
@@ -101,18 +165,26 @@ impl GameState for State {
                 }
 
                 {
+                    let palette = palette::current_palette(&self.ecs);
+                    let markers = self.ecs.fetch::<Settings>().markers;
                     let positions = self.ecs.read_storage::<Position>();
                     let renderables = self.ecs.read_storage::<Renderable>();
                     let belongs = self.ecs.read_storage::<BelongsTo>();
+                    let locks = self.ecs.read_storage::<RequiresItem>();
                     let current_place = self.ecs.fetch::<Place>();
-                    let mut data = (&positions, &renderables, &belongs).join().collect::<Vec<_>>();
+                    let mut data = (&positions, &renderables, &belongs, locks.maybe()).join().collect::<Vec<_>>();
                     data.sort_by(|&a, &b| b.1.render_order.cmp(&a.1.render_order));
-                    for (pos, rend, bel) in data {
+                    for (pos, rend, bel, lock) in data {
                         if bel.domain == *current_place {
-                            ctx.set(pos.x, pos.y, rend.fg, rend.bg, rend.glyph);
+                            let glyph = match markers && lock.is_some() {
+                                true => rltk::to_cp437(LOCKED_DOOR_CHAR),
+                                false => rend.glyph
+                            };
+                            ctx.set(pos.x, pos.y, palette.tint(rend.fg), palette.tint(rend.bg), glyphs::display_glyph(glyph));
                         }
                     }
                 }
+                palette::advance_transition(&self.ecs);
                 gui::draw(&self.ecs, ctx);
             }
         }
@@ -124,11 +196,23 @@ impl GameState for State {
                     MainMenuResult::NoSelection { selected } => run_state = RunState::Menu { menu_selection: selected },
                     MainMenuResult::Selected { selected } => {
                         match selected {
-                            MainMenuSelection::NewGame => run_state = RunState::EnterName,
+                            MainMenuSelection::NewGame => run_state = self.start_new_game(),
                             MainMenuSelection::LoadGame => {
                                 save_load_system::load_game(&mut self.ecs);
                                 run_state = RunState::Game;
                             }
+                            MainMenuSelection::Profile => {
+                                let selection = self.ecs.fetch::<Profiles>().active.unwrap_or(0);
+                                run_state = RunState::SelectProfile { selection };
+                            }
+                            MainMenuSelection::Achievements => run_state = RunState::Achievements,
+                            MainMenuSelection::Settings => run_state = RunState::Settings { selection: 0, in_game: false },
+                            MainMenuSelection::Controls => run_state = RunState::Controls { selection: 0, capturing: false, conflict: None, from_settings: false, in_game: false },
+                            MainMenuSelection::Language => {
+                                self.ecs.write_resource::<Settings>().set_language(i18n::language().next());
+                                profiles::save_settings(&self.ecs);
+                                run_state = RunState::Menu { menu_selection: MainMenuSelection::Language };
+                            }
                             MainMenuSelection::QuitGame => std::process::exit(0),
                             MainMenuSelection::Credits => {
                                 run_state = RunState::Credits;
@@ -137,34 +221,99 @@ impl GameState for State {
                     }
                 }
             }
-            RunState::EnterName => {
-                let done = gui::draw_enter_name(&mut self.ecs, ctx);
-                if done {
-                    let mut player_name = self.ecs.fetch_mut::<PlayerName>();
-                    let mut names = self.ecs.write_storage::<Name>();
-                    let players = self.ecs.read_storage::<Player>();
-                    for (name, _player) in (&mut names, &players).join() {
-                        name.name = String::from(player_name.name.as_str());
+            RunState::Controls { selection, capturing, conflict, from_settings, in_game } => {
+                run_state = match gui::draw_controls(&mut self.ecs, ctx, selection, capturing, conflict) {
+                    ControlsResult::NoResponse { selection, capturing, conflict } => RunState::Controls { selection, capturing, conflict, from_settings, in_game },
+                    ControlsResult::Done if from_settings => RunState::Settings { selection: 0, in_game },
+                    ControlsResult::Done => RunState::Menu { menu_selection: MainMenuSelection::Controls },
+                };
+            }
+            RunState::Paused { selected, confirm } => {
+                run_state = match gui::draw_pause_menu(&self.ecs, ctx, selected, confirm) {
+                    PauseMenuResult::NoResponse { selected, confirm } => RunState::Paused { selected, confirm },
+                    PauseMenuResult::Selected { selected } => match selected {
+                        PauseSelection::Resume => RunState::Game,
+                        PauseSelection::Save => RunState::SaveGame,
+                        PauseSelection::Load => {
+                            save_load_system::load_game(&mut self.ecs);
+                            RunState::Game
+                        }
+                        PauseSelection::Settings => RunState::Settings { selection: 0, in_game: true },
+                        PauseSelection::Help => RunState::Help,
+                        PauseSelection::QuitToMenu => {
+                            populate_new_game(self);
+                            RunState::Menu { menu_selection: MainMenuSelection::NewGame }
+                        }
+                    }
+                };
+            }
+            RunState::Settings { selection, in_game } => {
+                run_state = match gui::draw_settings(&mut self.ecs, ctx, selection) {
+                    SettingsResult::NoResponse { selection } => RunState::Settings { selection, in_game },
+                    SettingsResult::Controls => RunState::Controls { selection: 0, capturing: false, conflict: None, from_settings: true, in_game },
+                    SettingsResult::Done if in_game => RunState::Paused { selected: PauseSelection::Settings, confirm: None },
+                    SettingsResult::Done => RunState::Menu { menu_selection: MainMenuSelection::Settings },
+                };
+            }
+            RunState::Help => {
+                if gui::draw_help(&self.ecs, ctx) {
+                    run_state = RunState::Paused { selected: PauseSelection::Help, confirm: None };
+                }
+            }
+            RunState::LogHistory { scroll, filter } => {
+                run_state = match gui::draw_log_history(&self.ecs, ctx, scroll, filter) {
+                    LogHistoryResult::NoResponse { scroll, filter } => RunState::LogHistory { scroll, filter },
+                    LogHistoryResult::Done => RunState::Game,
+                };
+            }
+            RunState::SelectProfile { selection } => {
+                run_state = match gui::draw_select_profile(&self.ecs, ctx, selection) {
+                    ProfileMenuResult::NoResponse { selection } => RunState::SelectProfile { selection },
+                    ProfileMenuResult::Selected { index } => {
+                        let name = self.ecs.fetch::<Profiles>().profiles[index].name.clone();
+                        profiles::select_profile(&mut self.ecs, ctx, &name);
+                        RunState::Menu { menu_selection: MainMenuSelection::NewGame }
+                    }
+                    ProfileMenuResult::New => {
+                        self.ecs.write_resource::<PlayerName>().name.clear();
+                        RunState::EnterName
                     }
-                    run_state = RunState::Game;
+                };
+            }
+            RunState::Achievements => {
+                if gui::draw_achievements(&self.ecs, ctx) {
+                    run_state = RunState::Menu { menu_selection: MainMenuSelection::Achievements };
+                }
+            }
+            RunState::EnterName => {
+                let done = gui::draw_enter_name(&mut self.ecs, ctx, &input.typed);
+                let name = self.ecs.fetch::<PlayerName>().name.clone();
+                if done && !name.is_empty() {
+                    profiles::select_profile(&mut self.ecs, ctx, &name);
+                    run_state = RunState::Menu { menu_selection: MainMenuSelection::NewGame };
                 } else {
                     run_state = RunState::EnterName
                 }
             }
             RunState::Credits => {
-                gui::draw_credits(ctx);
+                gui::draw_credits(&self.ecs, ctx);
                 if let Some(_) = ctx.key {
                     run_state = RunState::Menu { menu_selection: MainMenuSelection::Credits };
                 }
             }
             RunState::Game => {
                 self.run_systems();
-                self.ecs.maintain();
-                run_state = player_input(self, ctx);
+                let requested = *self.ecs.fetch::<RunState>();
+                run_state = match requested {
+                    RunState::Game => player_input(self, ctx),
+                    _ => requested
+                };
             }
             RunState::SaveGame => {
                 save_load_system::save_game(&mut self.ecs);
-                run_state = RunState::Menu { menu_selection: MainMenuSelection::LoadGame }
+                let clock = *self.ecs.fetch::<GameClock>();
+                self.ecs.write_resource::<GameLog>().push(LogCategory::System, &clock, tr(CONSOLE_GAME_SAVED));
+                run_state = RunState::Game;
             }
             RunState::UseInventory => {
                 let (result, item) = gui::draw_use_item(self, ctx);
@@ -181,24 +330,25 @@ impl GameState for State {
                         let target_pos = self.ecs.fetch::<TargetedPosition>();
                         let mut requires_item = self.ecs.write_storage::<RequiresItem>();
                         let mut log = self.ecs.write_resource::<GameLog>();
+                        let mut events = self.ecs.write_resource::<GameEvents>();
                         let mut map = self.ecs.write_resource::<Map>();
                         let entities = self.ecs.entities();
 
                         for (pos, req, ent) in (&positions, &requires_item, &entities).join() {
                             if pos.x == target_pos.x && pos.y == target_pos.y {
                                 if req.key == item {
-                                    log.entries.push(format!("Esya kullanildi: {}", item.to_string()));
+                                    events.push(GameEvent::ItemUsed(item));
+                                    events.push(GameEvent::DoorUnlocked(ent));
                                     if self.ecs.read_storage::<PermanentItem>().get(ent).is_none() {
                                         self.ecs.write_storage::<Stored>().remove(ent);
                                     }
                                     if self.ecs.read_storage::<Portal>().get(ent).is_some() {
-                                        println!("Changed to portal");
                                         map.tiles[Map::xy_to_tile(pos.x, pos.y)] = TileType::Portal;
                                     }
                                     barriers_to_remove.push(ent);
                                     run_state = RunState::Game;
                                 } else {
-                                    log.entries.push(String::from("Yanlis esya"));
+                                    log.push(LogCategory::Item, &self.ecs.fetch::<GameClock>(), tr(CONSOLE_WRONG_ITEM));
                                 }
                             }
                         }
@@ -216,18 +366,51 @@ impl GameState for State {
                         run_state = RunState::Game;
                     }
                     NpcInteractionResult::NextDialogue { index } => {
+                        self.ecs.write_resource::<GameClock>().advance(CLOCK_MINUTES_PER_DIALOGUE_PAGE);
                         run_state = RunState::InteractNpc { index };
                     }
                 }
             }
-            RunState::GameOver => {
-                gui::draw_game_over(ctx);
-                if let Some(_) = ctx.key {
-                    std::process::exit(0);
-                }
+            RunState::GameOver { selected, exported } => {
+                run_state = match gui::draw_game_over(&self.ecs, ctx, selected, exported) {
+                    GameOverResult::NoResponse { selected } => RunState::GameOver { selected, exported },
+                    GameOverResult::Selected { selected: GameOverSelection::MainMenu } => {
+                        populate_new_game(self);
+                        RunState::Menu { menu_selection: MainMenuSelection::NewGame }
+                    }
+                    GameOverResult::Selected { selected: GameOverSelection::ExportReport } => {
+                        let written = Report::new(&self.ecs).export(&self.ecs.fetch::<Settings>()).is_ok();
+                        RunState::GameOver { selected, exported: Some((GameOverSelection::ExportReport, written)) }
+                    }
+                    GameOverResult::Selected { selected: GameOverSelection::ExportStudySheet } => {
+                        let written = study_sheet::export_study_sheet(&self.ecs).is_ok();
+                        RunState::GameOver { selected, exported: Some((GameOverSelection::ExportStudySheet, written)) }
+                    }
+                };
+            }
+        }
+
+        if matches!(run_state, RunState::GameOver { .. }) && !matches!(previous_run_state, RunState::GameOver { .. }) {
+            profiles::record_playthrough(&self.ecs);
+        }
+
+        if matches!(run_state, RunState::InteractNpc { .. }) && !matches!(previous_run_state, RunState::InteractNpc { .. }) {
+            if let Some(npc) = targeted_npc::<Npc>(&self.ecs) {
+                self.ecs.write_resource::<GameEvents>().push(GameEvent::DialogueStarted(npc));
             }
         }
 
+        if previous_run_state == RunState::Game {
+            match run_state {
+                RunState::UseInventory => hints::show_hint(&self.ecs, Hint::LockedDoor),
+                RunState::InteractNpc { .. } => hints::show_hint(&self.ecs, Hint::Npc),
+                _ => {}
+            }
+        }
+
+        self.run_event_consumers();
+        palette::apply_settings(ctx, &self.ecs.fetch::<Settings>());
+
         {
             let mut state_writer = self.ecs.write_resource::<RunState>();
             *state_writer = run_state;
@@ -235,125 +418,142 @@ impl GameState for State {
     }
 }
 
-fn main() -> rltk::BError {
-    use rltk::RltkBuilder;
-    let context = RltkBuilder::simple80x50()
-        .with_title("Tarih Oyunu")
-        .with_tile_dimensions(12, 12)
-        .build()?;
-    let mut gs = State { ecs: World::new() };
+/// Reads `--generate` and `--seed <number>` from the command line, the hand-made streets are used
+/// unless one of them is given and `--generate` alone rolls a new seed
+fn map_generation_from_args() -> MapGeneration {
+    let mut generation = MapGeneration::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--generate" if !generation.enabled => {
+                generation = MapGeneration { seed: rltk::RandomNumberGenerator::new().range(1, 1_000_000), enabled: true };
+            }
+            "--seed" => {
+                if let Some(seed) = args.next().and_then(|s| s.parse().ok()) {
+                    generation = MapGeneration { seed, enabled: true };
+                }
+            }
+            _ => {}
+        }
+    }
+    generation
+}
 
-    gs.ecs.register::<Position>();
-    gs.ecs.register::<TargetedPosition>();
-    gs.ecs.register::<Renderable>();
-    gs.ecs.register::<Player>();
-    gs.ecs.register::<Name>();
-    gs.ecs.register::<Item>();
-    gs.ecs.register::<Stored>();
-    gs.ecs.register::<Impassable>();
-    gs.ecs.register::<RequiresItem>();
-    gs.ecs.register::<ContainsItem>();
-    gs.ecs.register::<RequiresItems>();
-    gs.ecs.register::<ContainsItems>();
-    gs.ecs.register::<PermanentItem>();
-    gs.ecs.register::<SimpleMarker<SerializeMe>>();
-    gs.ecs.register::<SerializationHelper>();
-    gs.ecs.register::<Portal>();
-    gs.ecs.register::<BelongsTo>();
-    gs.ecs.register::<Npc>();
-    gs.ecs.register::<Objective>();
-    gs.ecs.register::<Interaction>();
-    gs.ecs.register::<DormantPosition>();
-    gs.ecs.register::<RevealerInformation>();
-    gs.ecs.register::<PlayerName>();
+/// Reads `--lang <id>` from the command line, e.g. `--lang en`
+fn language_from_args() -> Option<Language> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--lang" {
+            return args.next().and_then(|id| Language::from_id(&id));
+        }
+    }
+    None
+}
 
-    gs.ecs.insert(SimpleMarkerAllocator::<SerializeMe>::new());
-    gs.ecs.insert(Place::Home);
+/// Throws away whatever is in the world and sets up the start of a fresh game
+fn populate_new_game(gs: &mut State) {
+    gs.ecs.delete_all();
+    gs.ecs.maintain();
+    gs.ecs.insert(Place::HOME);
+    let generation = *gs.ecs.fetch::<MapGeneration>();
+    gs.ecs.insert(rltk::RandomNumberGenerator::seeded(generation.seed));
     gs.ecs.insert(PlayerName { name: "".to_string() });
+    gs.ecs.insert(triggers::Flags::default());
+    gs.ecs.insert(GameClock::default());
+    gs.ecs.insert(GameStatistics::new(Place::HOME));
+    gs.ecs.insert(save_load_system::LastSave { turns: None });
 
     let player_coord = (25, 20);
-    let log = GameLog::new(vec!["Oyuna hosgeldin!".to_string()]);
-    let player_entity = spawner::build_player(&mut gs, String::from(""), player_coord);
+    let mut log = GameLog::default();
+    log.push(LogCategory::System, &gs.ecs.fetch::<GameClock>(), tr(CONSOLE_WELCOME));
+    let player_entity = spawner::build_player(gs, String::from(""), player_coord);
 
-    spawner::build_portal(&mut gs, String::from("Guney Kapisi"), Place::Home, HOME_TO_SCHOOL_PORTAL_COORD, Place::SchoolSouth, SCHOOL_SOUTH_FROM_HOME_COORD);
-    spawner::build_portal(&mut gs, String::from("Ev Kapisi"), Place::SchoolSouth, SCHOOL_TO_HOME_PORTAL_COORD, Place::Home, HOME_FROM_SCHOOL_COORD);
+    spawner::build_portal(gs, String::from("portal.south_gate"), Place::HOME, HOME_TO_SCHOOL_PORTAL_COORD, Place::SCHOOL_SOUTH, SCHOOL_SOUTH_FROM_HOME_COORD);
+    spawner::build_portal(gs, String::from("portal.home_gate"), Place::SCHOOL_SOUTH, SCHOOL_TO_HOME_PORTAL_COORD, Place::HOME, HOME_FROM_SCHOOL_COORD);
 
-    spawner::build_portal(&mut gs, String::from("M2152 Kapisi"), Place::SchoolSouth, SCHOOL_SOUTH_TO_CLASS_PORTAL_COORD, Place::Class, CLASS_FROM_SCHOOL_COORD);
-    spawner::build_portal(&mut gs, String::from("Guney Kapisi"), Place::Class, CLASS_TO_SCHOOL_PORTAL_COORD, Place::SchoolSouth, SCHOOL_SOUTH_FROM_CLASS_COORD);
+    spawner::build_portal(gs, String::from("portal.class_gate"), Place::SCHOOL_SOUTH, SCHOOL_SOUTH_TO_CLASS_PORTAL_COORD, Place::CLASS, CLASS_FROM_SCHOOL_COORD);
+    spawner::build_portal(gs, String::from("portal.south_gate"), Place::CLASS, CLASS_TO_SCHOOL_PORTAL_COORD, Place::SCHOOL_SOUTH, SCHOOL_SOUTH_FROM_CLASS_COORD);
 
-    spawner::build_portal(&mut gs, String::from("Kuzey Kapisi"), Place::SchoolSouth, SCHOOL_SOUTH_TO_SCHOOL_NORTH_PORTAL_COORD, Place::SchoolNorth, SCHOOL_NORTH_FROM_SCHOOL_SOUTH_COORD);
-    spawner::build_portal(&mut gs, String::from("Guney Kapisi"), Place::SchoolNorth, SCHOOL_NORTH_TO_SCHOOL_SOUTH_PORTAL_COORD, Place::SchoolSouth, SCHOOL_SOUTH_FROM_SCHOOL_NORTH_COORD);
+    spawner::build_portal(gs, String::from("portal.north_gate"), Place::SCHOOL_SOUTH, SCHOOL_SOUTH_TO_SCHOOL_NORTH_PORTAL_COORD, Place::SCHOOL_NORTH, SCHOOL_NORTH_FROM_SCHOOL_SOUTH_COORD);
+    spawner::build_portal(gs, String::from("portal.south_gate"), Place::SCHOOL_NORTH, SCHOOL_NORTH_TO_SCHOOL_SOUTH_PORTAL_COORD, Place::SCHOOL_SOUTH, SCHOOL_SOUTH_FROM_SCHOOL_NORTH_COORD);
 
-    spawner::build_portal(&mut gs, String::from("Kutuphane Kapisi"), Place::SchoolNorth, SCHOOL_NORTH_TO_LIBRARY_PORTAL_COORD, Place::Library, LIBRARY_FROM_SCHOOL_NORTH_COORD);
-    spawner::build_portal(&mut gs, String::from("Kuzey Kapisi"), Place::Library, LIBRARY_TO_SCHOOL_NORTH_PORTAL_COORD, Place::SchoolNorth, SCHOOL_NORTH_FROM_LIBRARY_COORD);
+    spawner::build_portal(gs, String::from("portal.library_gate"), Place::SCHOOL_NORTH, SCHOOL_NORTH_TO_LIBRARY_PORTAL_COORD, Place::LIBRARY, LIBRARY_FROM_SCHOOL_NORTH_COORD);
+    spawner::build_portal(gs, String::from("portal.north_gate"), Place::LIBRARY, LIBRARY_TO_SCHOOL_NORTH_PORTAL_COORD, Place::SCHOOL_NORTH, SCHOOL_NORTH_FROM_LIBRARY_COORD);
 
-    spawner::build_dormant_door(&mut gs, String::from("Gizli Kapi"), Place::SchoolSouth, SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD, Place::OttomanMain, OTTOMAN_FROM_SCHOOL_COORD, ItemName::SecretGateKey,
+    spawner::build_dormant_door(gs, String::from("portal.secret_gate"), Place::SCHOOL_SOUTH, SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD, Place::OTTOMAN_MAIN, OTTOMAN_FROM_SCHOOL_COORD, ItemName::SecretGateKey,
                                 (SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD.0 - 2, SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD.0 + 2), (SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD.1, SCHOOL_SOUTH_TO_OTTOMAN_PORTAL_COORD.1 + 2),
                                 ItemName::SecretGateKey, TileType::Wall);
 
-    spawner::build_door(&mut gs, String::from("Bati Cikisi"), Place::OttomanMain, OTTOMAN_TO_LEFT_PORTAL_COORD, Place::OttomanLeft, OTTOMAN_LEFT_FROM_MAIN_COORD, ItemName::OttomanKey1);
-    spawner::build_portal(&mut gs, String::from("Meydan Kapisi"), Place::OttomanLeft, OTTOMAN_LEFT_TO_MAIN_PORTAL_COORD, Place::OttomanMain, OTTOMAN_FROM_LEFT_COORD);
+    spawner::build_door(gs, String::from("portal.west_exit"), Place::OTTOMAN_MAIN, OTTOMAN_TO_LEFT_PORTAL_COORD, Place::OTTOMAN_LEFT, OTTOMAN_LEFT_FROM_MAIN_COORD, ItemName::OttomanKey1);
+    spawner::build_portal(gs, String::from("portal.square_gate"), Place::OTTOMAN_LEFT, OTTOMAN_LEFT_TO_MAIN_PORTAL_COORD, Place::OTTOMAN_MAIN, OTTOMAN_FROM_LEFT_COORD);
+
+    spawner::build_door(gs, String::from("portal.north_exit"), Place::OTTOMAN_MAIN, OTTOMAN_TO_TOP_PORTAL_COORD, Place::OTTOMAN_TOP, OTTOMAN_TOP_FROM_MAIN_COORD, ItemName::OttomanKey2);
+    spawner::build_portal(gs, String::from("portal.square_gate"), Place::OTTOMAN_TOP, OTTOMAN_TOP_TO_MAIN_PORTAL_COORD, Place::OTTOMAN_MAIN, OTTOMAN_FROM_TOP_COORD);
+
+    spawner::build_door(gs, String::from("portal.east_exit"), Place::OTTOMAN_MAIN, OTTOMAN_TO_RIGHT_PORTAL_COORD, Place::OTTOMAN_RIGHT, OTTOMAN_RIGHT_FROM_MAIN_COORD, ItemName::OttomanKey3);
+    spawner::build_portal(gs, String::from("portal.square_gate"), Place::OTTOMAN_RIGHT, OTTOMAN_RIGHT_TO_MAIN_PORTAL_COORD, Place::OTTOMAN_MAIN, OTTOMAN_FROM_RIGHT_COORD);
 
-    spawner::build_door(&mut gs, String::from("Kuzey Cikisi"), Place::OttomanMain, OTTOMAN_TO_TOP_PORTAL_COORD, Place::OttomanTop, OTTOMAN_TOP_FROM_MAIN_COORD, ItemName::OttomanKey2);
-    spawner::build_portal(&mut gs, String::from("Meydan Kapisi"), Place::OttomanTop, OTTOMAN_TOP_TO_MAIN_PORTAL_COORD, Place::OttomanMain, OTTOMAN_FROM_TOP_COORD);
+    spawner::build_trigger(gs, Place::OTTOMAN_MAIN, TriggerArea::Tile { x: OTTOMAN_FROM_SCHOOL_COORD.0, y: OTTOMAN_FROM_SCHOOL_COORD.1 }, vec!(),
+                           vec!(TriggerEffect::Log(String::from(CONSOLE_TIME_TRAVEL))), vec!(), false);
 
-    spawner::build_door(&mut gs, String::from("Dogu Cikisi"), Place::OttomanMain, OTTOMAN_TO_RIGHT_PORTAL_COORD, Place::OttomanRight, OTTOMAN_RIGHT_FROM_MAIN_COORD, ItemName::OttomanKey3);
-    spawner::build_portal(&mut gs, String::from("Meydan Kapisi"), Place::OttomanRight, OTTOMAN_RIGHT_TO_MAIN_PORTAL_COORD, Place::OttomanMain, OTTOMAN_FROM_RIGHT_COORD);
+    spawner::build_portal(gs, String::from("portal.south_exit"), Place::OTTOMAN_MAIN, OTTOMAN_TO_BOTTOM_PORTAL_COORD, Place::OTTOMAN_BOTTOM, OTTOMAN_BOTTOM_FROM_MAIN_COORD);
+    spawner::build_portal(gs, String::from("portal.square_gate"), Place::OTTOMAN_BOTTOM, OTTOMAN_BOTTOM_TO_MAIN_PORTAL_COORD, Place::OTTOMAN_MAIN, OTTOMAN_FROM_BOTTOM_COORD);
 
-    spawner::build_dormant_door(&mut gs, String::from("Zaman Kapisi"), Place::OttomanMain, OTTOMAN_TO_SCHOOL_PORTAL_COORD, Place::SchoolSouth, SCHOOL_SOUTH_FROM_OTTOMAN_COORD, ItemName::OttomanKeyMain,
+    spawner::build_dormant_door(gs, String::from("portal.time_gate"), Place::OTTOMAN_MAIN, OTTOMAN_TO_SCHOOL_PORTAL_COORD, Place::SCHOOL_SOUTH, SCHOOL_SOUTH_FROM_OTTOMAN_COORD, ItemName::OttomanKeyMain,
                                 (OTTOMAN_MAIN_X, OTTOMAN_MAIN_X + OTTOMAN_MAIN_WIDTH), (OTTOMAN_MAIN_Y, OTTOMAN_MAIN_Y + OTTOMAN_MAIN_HEIGHT), ItemName::OttomanKeyMain, TileType::Floor);
 
 
-    spawner::build_active_item(&mut gs, ItemName::Book, Place::Library, (19, 19), true);
-    spawner::build_active_item(&mut gs, ItemName::Book, Place::Library, (20, 20), true);
-    spawner::build_dormant_item(&mut gs, ItemName::SecretGateKey);
+    spawner::build_active_item(gs, ItemName::Book, Place::LIBRARY, (19, 19), true);
+    spawner::build_active_item(gs, ItemName::Book, Place::LIBRARY, (20, 20), true);
+    spawner::build_dormant_item(gs, ItemName::SecretGateKey);
+    spawner::build_active_item(gs, ItemName::PetFood, Place::HOME, (22, 17), false);
 
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanKey1);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardPoem);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardBookCover);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardGlue);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanCombinedRewardPoemBook);
+    spawner::build_dormant_item(gs, ItemName::OttomanKey1);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardPoem);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardBookCover);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardGlue);
+    spawner::build_dormant_item(gs, ItemName::OttomanCombinedRewardPoemBook);
 
 
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanKey2);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardMosquePart1);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardMosquePart2);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanCombinedRewardMosqueModel);
+    spawner::build_dormant_item(gs, ItemName::OttomanKey2);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardMosquePart1);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardMosquePart2);
+    spawner::build_dormant_item(gs, ItemName::OttomanCombinedRewardMosqueModel);
 
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanKey3);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardNotePaper);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardCanvas);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanRewardClay);
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanCombinedRewardWeirdCollage);
+    spawner::build_dormant_item(gs, ItemName::OttomanKey3);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardNotePaper);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardCanvas);
+    spawner::build_dormant_item(gs, ItemName::OttomanRewardClay);
+    spawner::build_dormant_item(gs, ItemName::OttomanCombinedRewardWeirdCollage);
 
-    spawner::build_dormant_item(&mut gs, ItemName::OttomanKeyMain);
+    spawner::build_dormant_item(gs, ItemName::OttomanKeyMain);
 
-    spawner::build_npc_human(&mut gs, "Taylan Hoca", Place::Class, (CLASS_X + CLASS_WIDTH - 2, CLASS_Y + 2),
-                             vec!(vec!("Merhabalar.", "Bugun derste gosterecegim kitaplari kutuphanede unutmusum."),
-                                  vec!("Rica etsem kitaplari getirebilir misiniz?"),
-                                  vec!("Super, iki tane daha olmali."),
-                                  vec!("Cok tesekkurler!", "Sonuncuyu da alabilir miyim?"),
-                                  vec!("Demek sizde degil...", "O zaman size bu anahtari veriyorum.", "Guney Kampus'te biraz gezerseniz bu anahtarin kullnailacagi bir kapi bulacaksiniz ve kitabim de o kapinin ardinda."),
-                                  vec!("Iyi gunler, kitabi bulmayi unutmayin.")),
+    spawner::build_npc_human(gs, "npc.taylan.name", Place::CLASS, (CLASS_X + CLASS_WIDTH - 2, CLASS_Y + 2),
+                             vec!(vec!("npc.taylan.0.0", "npc.taylan.0.1"),
+                                  vec!("npc.taylan.1.0"),
+                                  vec!("npc.taylan.2.0"),
+                                  vec!("npc.taylan.3.0", "npc.taylan.3.1"),
+                                  vec!("npc.taylan.4.0", "npc.taylan.4.1", "npc.taylan.4.2"),
+                                  vec!("npc.taylan.5.0")),
                              Some(vec!(ItemName::Book, ItemName::Book)),
                              Some(vec!(ItemName::SecretGateKey)),
                              vec!(1, 2),
                              vec!(4),
                              vec!(1, 4));
 
-    spawner::build_npc_human(&mut gs, "Gizemli Karakter", Place::OttomanMain, (OTTOMAN_MAIN_X + OTTOMAN_MAIN_WIDTH / 2 + 2, OTTOMAN_MAIN_Y + OTTOMAN_MAIN_HEIGHT / 2),
-                             vec!(vec!("Merhabalar gelecekten gelen!", "Yuzundeki ifadeden anladigim kadariyla oldukca sasirmis durumdasin.", "O yuzden aciklamama izin ver:"),
-                                  vec!("Taylan Hoca, tarihi ogrenmek icin bir caba icerisinde olmayanlara iyi bir ders vermek icin onlari gecmise yollar.", "Bu sefer de o sansli kisi sensin belli ki."),
-                                  vec!("Eger kendi zamanina donmek istiyorsan dersini burada, yasayarak ogrenmek zorundasin!", "Bu seneki konu Osmanli'nin son dönemlerinde sanat alininda yasadigi degisimler.", "Gordugun kapilarin arkasinda, konu hakkinda seni ilgilendirecek insanlar bulunuyor.", "Her birini iyice dinle ve isin bitince bana geri don."),
-                                  vec!("Hadi bakalim!", "Ilk ders ile basla."),
-                                  vec!("Demek ilk dersi dinledin.", "Afferin!"),
-                                  vec!("Simdi sirada ikinci ders var."),
-                                  vec!("Dersi dinle ve bana geri don!"),
-                                  vec!("Demek ikinci dersi dinledin.", "Afferin!"),
-                                  vec!("Simdi ucuncu ders var."),
-                                  vec!("Dersi dinle ve bana geri don!"),
-                                  vec!("Demek ucuncu dersi dinledin.", "Afferin!", "Artik kendi zamanina donebilirsin!"),
-                                  vec!("Kendine iyi bak.")),
+    spawner::build_npc_human(gs, "npc.mysterious.name", Place::OTTOMAN_MAIN, (OTTOMAN_MAIN_X + OTTOMAN_MAIN_WIDTH / 2 + 2, OTTOMAN_MAIN_Y + OTTOMAN_MAIN_HEIGHT / 2),
+                             vec!(vec!("npc.mysterious.0.0", "npc.mysterious.0.1", "npc.mysterious.0.2"),
+                                  vec!("npc.mysterious.1.0", "npc.mysterious.1.1"),
+                                  vec!("npc.mysterious.2.0", "npc.mysterious.2.1", "npc.mysterious.2.2", "npc.mysterious.2.3"),
+                                  vec!("npc.mysterious.3.0", "npc.mysterious.3.1"),
+                                  vec!("npc.mysterious.4.0", "npc.mysterious.4.1"),
+                                  vec!("npc.mysterious.5.0"),
+                                  vec!("npc.mysterious.6.0"),
+                                  vec!("npc.mysterious.7.0", "npc.mysterious.7.1"),
+                                  vec!("npc.mysterious.8.0"),
+                                  vec!("npc.mysterious.9.0"),
+                                  vec!("npc.mysterious.10.0", "npc.mysterious.10.1", "npc.mysterious.10.2"),
+                                  vec!("npc.mysterious.11.0")),
                              Some(vec!(ItemName::OttomanCombinedRewardPoemBook, ItemName::OttomanCombinedRewardMosqueModel, ItemName::OttomanCombinedRewardWeirdCollage)),
                              Some(vec!(ItemName::OttomanKey1, ItemName::OttomanKey2, ItemName::OttomanKey3, ItemName::OttomanKeyMain)),
                              vec!(3, 6, 9),
@@ -361,36 +561,33 @@ fn main() -> rltk::BError {
                              vec!(2, 5, 8, 10));
 
 
-    spawner::build_npc_human(&mut gs, "Osman Bey", Place::OttomanLeft, (OTTOMAN_LEFT_X + OTTOMAN_LEFT_WIDTH / 2, OTTOMAN_LEFT_Y + OTTOMAN_LEFT_HEIGHT / 2 - 1),
-                             vec!(vec!("Medeniyetler, birbirinden ayri dusunulemezler. Bu sebepten mutevellit, medeniyetler arasindaki etkilesimin bir sekilde saglanmasi gerekir. Bu etkilesim de tercume sayesinde paylasilir. Tercumeye gerekli degeri ilk verenler Tanzimat dusunurleriydi. Sinasi’nin 1869’da Fransiz siirlerini cevirmesinden sonra 1880’e kadar sadece 25 siir tercume edildi. Neyse ki 1880’den sonra bati ulkelerine ait siirlerin tercume edilip dilimize aktarilmasi bir hayli hiz kazandi."),
-                                  vec!("1859-1901 yillari arasinda Bati edebiyatindan en az 802 siir dilimize tercume edildi ve bu sayede bizim siirlerimizde de farkli formlar ve konular gorulmeye baslandi. Bu farkli konulardan belki de en onemlisi, tekrardan bireylerin gunluk problemlerinin siirlere aktarilmasiydi. O donemin onemli sair-mutercimlerine Muallim Naci, Ahmed Rasim, Recâizâde Mahmud Ekrem, Şinasi ve Nâbizâde Nâzim gibi isimleri; daha cok mutercimlikleri ile bilinen isimlere Halil Edib, Ahmed Refik ve Ali Riza Seyfi gibi isimleri ornek verebilirim."),
-                                  vec!("Seninle ilginc bir bilgi paylasmak istiyorum: Su yenililesme karsiti diye anilan Muallim Naci var ya; belirttigim yillar arasinda 62 manzume ile en fazla tercume yapan sairimiz odur.  Yapilan siir cevirilerinin buyuk bir cogunlugu 650 siir ile Fransiz edebiyatina aitti ve devaminda Ingiliz ve Alman edebiyati geliyordu. Yapilan tercumelerin gazetelerde yayinlanmasi ve gunluk hayatin bir parcasi haline gelmesi, Bati siirine olan ilgiyi daha da arttirmistir. Bu ilgiden kaynakli olarak daha once de belirttigim gibi yeni siir formlari ortaya cikmaya basladi."),
-                                  vec!("Bu durum hem bir zenginlige ve duzensizlige kapi acmis oldu. Neden oldugunu anlayamasam da fabller Bati siir dunyasi icerisinde onemli bir yer kaplamakta idi ve tercumeler sayesinde bizim edebiyatimizda da benimsenmis oldu. Yapilan tercumelerin hangi eserlere ait olduklarina bakarken, mutercimlerin kisisel tercihlerinin de bu secimlerde onemli roller oynadigini unutmamak gerekir. Mesela Recaizade Ekrem daha cok La Fontaine’nin fabblerini tercume etmeyi tercih etmistir. Naci ise tercihini Florian’in eserlerinden yana kullanmistir."),
-                                  vec!("Umarim anlattiklarim faydali olmustur.")
-                             ),
+    spawner::build_npc_human(gs, "npc.osman.name", Place::OTTOMAN_LEFT, (OTTOMAN_LEFT_X + OTTOMAN_LEFT_WIDTH / 2, OTTOMAN_LEFT_Y + OTTOMAN_LEFT_HEIGHT / 2 - 1),
+                             vec!(vec!("npc.osman.0.0"),
+                                  vec!("npc.osman.1.0"),
+                                  vec!("npc.osman.2.0"),
+                                  vec!("npc.osman.3.0"),
+                                  vec!("npc.osman.4.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardPoem)),
                              vec!(),
                              vec!(3),
                              vec!(3));
-    spawner::build_npc_human(&mut gs, "Zeliha Hanim", Place::OttomanLeft, (OTTOMAN_LEFT_X + OTTOMAN_LEFT_WIDTH / 2 - 1, OTTOMAN_LEFT_Y + OTTOMAN_LEFT_HEIGHT / 2 + 1),
-                             vec!(vec!("Tanzimar sonrasi edebiyatinin demirbaslari sayilabilecek kisilerinden Sinasi, Namik Kemal, Recaizade Mahmut Ekrem gibi isimler duzenli bir egitimden gecmediler. Bunun yerine ozel dersler almislardir. Hepsi Fransiz lisanina hakimdiler. Tanzimat’tan sonra baslayan okullasmanin urunu olan okullarda Arapca ogretilmemekteydi. Arapca ve Farsca eserler yerine bati edebiyati eserleri daha cazip gorulmekte, bu eserler okutulmakta, dolayisiyla edebiyati anlayisi da buna gore sekillenmekteydi."),
-                                  vec!("Namik Kemal, yayimladigi makalesinde (Lisân-i Osmanînin Edebiyati Hakkinda Bazi Mulâhazâti Şamildir) bati rhe- torique’inin prensiplerinden olan hakikat ve tabiata uygunlugu ozellikle vurgulamistir. Namik Kemal’in Avrupa’ya gitmeden Batili eserlerin etkisinde kaldigi buradan anlasilabilir. Bunun devaminda Suleyman Pasa “Mebani’l Insa” adinda, Ali Cemaleddin “Arûz-t Turkî” adinda, Mihalicli Mustafa Efendi ise “Zubdetu’l-Beyan” adinda eserler yayimlarlar ancak hicbiri yeni edebiyat icin bir oncu gorevi goremez."),
-                                  vec!("Derken 1879’da Recaizade Mahmut Ekrem “Talim-i Edebiyat”’i tas baski halinde cikarmistir. Eserinde edebiyatin degistiginden, bu yeni edebiyatin aciklanmasi gerektiginden bahsetmektedir ve bu ihtiyaci karsilamaya calismistir. Namik Kemal, yazilanlarin konusma dilinde olmasi gerektigini hem sozlerin hem de hayallerin milli olmasi gerektigini, dolayisiyla edebiyati yaklasmakta oldugu Batili fikirlerden de korumak gerektigini vurgulamistir. Milliyet ve vatan meselelerini on plana cikararak yeni bir anlayis ortaya koymustur."),
-                                  vec!("Dinledigin icin tesekkur ederim.")
-                             ),
+    spawner::build_npc_human(gs, "npc.zeliha.name", Place::OTTOMAN_LEFT, (OTTOMAN_LEFT_X + OTTOMAN_LEFT_WIDTH / 2 - 1, OTTOMAN_LEFT_Y + OTTOMAN_LEFT_HEIGHT / 2 + 1),
+                             vec!(vec!("npc.zeliha.0.0"),
+                                  vec!("npc.zeliha.1.0"),
+                                  vec!("npc.zeliha.2.0"),
+                                  vec!("npc.zeliha.3.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardBookCover)),
                              vec!(),
                              vec!(2),
                              vec!(2));
 
-    spawner::build_npc_human(&mut gs, "Zeyneb Hanim", Place::OttomanLeft, (OTTOMAN_LEFT_X + OTTOMAN_LEFT_WIDTH / 2 - 1, OTTOMAN_LEFT_Y + OTTOMAN_LEFT_HEIGHT / 2 + 3),
-                             vec!(vec!("Devletimizin Bati karsisinda surekli guc kaybettigi donemlerde, aydinlarimiz edebiyati halki yasadigi bunalimdan biraz da olsa kurtarabilmek adina kullanmislardir. Bu sebepten dolayi son donem edebiyatimizda sosyal, siyasal, kulturel problemlere atiflara oldukca rastlanir. Son donem yazarlarimizdan Munif Paşa, Ahmet Mithat Efendi, Sadullah Paşa gibi isimler donemin fikir hareketlerinden etkilenerek bu fikirleri eserlerine yansitmislardir. Batidan bizim topraklarimiza ulasan rasyonalist akim da bu fikir akimlarindan biridir."),
-                                  vec!("Tercume-i Telemak cevirisi, direkt olarak bati dusuncesine dayanan bir kitaptir mesela.Namik Kemal icin cok degerli olarak gorulmesi ve Sinasi tarafindan ikinci baskisinin cevrilmesi, son donem edebiyatcilarimizin rasyonalizm ve realizmin etkisi altinda kaldigini gosterir. Baska bir ornek ise, Mumif Pasa’nin Mecmua-i Funun’da arka arkaya yayinladigi makaleler icerisinde batiya ait calismalardan faydalanilmasi ve bazi calismalarin dogrudan cevirilmesidir."),
-                                  vec!("Belirtmek istedigim baska bir durum ise, Fransiz aydinlanmasinda buyuk rol oynayan Montesqieu, Voltaire, Jean Jack Rousseau ve Diderot’un akla dayali ansiklopedik bilgiyi edebiyatlari ile birlestirme endiselerini; 1839-1896 yillari arasinda eser ureten edebiyatcilarimizin cogunda da gorebilmekteyiz."),
-                                  vec!("Iyi gunler dilerim.")
-                             ),
+    spawner::build_npc_human(gs, "npc.zeyneb.name", Place::OTTOMAN_LEFT, (OTTOMAN_LEFT_X + OTTOMAN_LEFT_WIDTH / 2 - 1, OTTOMAN_LEFT_Y + OTTOMAN_LEFT_HEIGHT / 2 + 3),
+                             vec!(vec!("npc.zeyneb.0.0"),
+                                  vec!("npc.zeyneb.1.0"),
+                                  vec!("npc.zeyneb.2.0"),
+                                  vec!("npc.zeyneb.3.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardGlue)),
                              vec!(),
@@ -398,29 +595,26 @@ fn main() -> rltk::BError {
                              vec!(2));
 
 
-    spawner::build_npc_human(&mut gs, "Ali Bey", Place::OttomanTop, (OTTOMAN_TOP_X + OTTOMAN_TOP_WIDTH / 2 + 1, OTTOMAN_TOP_Y + OTTOMAN_TOP_HEIGHT / 2),
-                             vec!(vec!("18. yuzyilin baslarindan itibaren Osmanli’nin Bati’nin gerisinde kalmasi, Bati’ya olan ilgiyi arttirmis ve Bati’yi ornek alma istegini kacinilmaz kilmisti. Istanbul’da bulunan Nuruosmaniye Camisi, Bati etkisinin mimari alanda gorulmeye baslandigi ilk orneklerdendir. Ne gibi bir farki vardi diyecek olursan ; avlusu klasik forma sahip diger camilere nazaran oval bir bicimdeydi. Anadolu camilerine deginecek olursak ise; batidan esinlenilen yuvarlak kemerler bulundurmalari, agirlik kulelerine her zaman olmasa da yeni bicimler verilmesi Bati’dan etkilendigimizin baska bir gostergesidir."),
-                                  vec!("Camilere yerlestirilen kapilarin uzerlerindeki motifler icin de zaman icinde batili tarzda motifler kullanildigini goruyoruz. Baska bir ornek ise 1895 tarihinde tamamlanan Soke Haci Ziya Bey Camii’sinin cephesi itibariyle adete Avrupai bir kosku andirmasidir. Turbeler acisindan bakacak olursak eger, 18. ve 19. Yuzyillar arasinda Anadolu turbe mimarisi onemini giderek yitirmis, sanat degerini kaybetmis ve cagin modasina uyum saglayamamistir. Medreseler, hanlar ve cesmeler, gerek Bati gerek baska fikir akimlari tarafindan etkilenmeyerek, ozlerinden pek kopmamislardir."),
-                                  vec!("Saraylar, biraz once bahsetmis oldugumuz yapilara nazaran, kendi kulturumuze ait unsurlari bulundurmanin yaninda, daha fazla Avrupai fikirler ile yapilmis unsurlari bunyesinde bulundurmaktadir. Gunluk hayata uyum saglamayi basaran han, carsi gibi yapilar varliklarini surdurmeye devam etmislerdir. Diger geleneksel yapilar ise toplumdaki ve kulturdeki degisimlere uyum saglayamadiklarindan mutevellit onemlerini yitirerek sayica azalmaya baslamislardir."),
-                                  vec!("Bunun onemli sebeplerinden biri Bati’ya duyulan ozenti sonucunda bakis acilarinin degismesi ve yeni ihtiyaclarin ortaya cikmasidir. Onemini yitirmeyen en onemli yapilar camiilerdir ancak gorunus acisindan daha once de bahsettigim gibi degisimler gecirmisler, batili bicimde gorunum kazanmislardir. Bu degisimlerin sebebi olan Batili gelisme donemleri ise belirli bir sira ile degil, daha cok karma olarak uygulanmistir. Buna ek olarak, camiilerde batili tarzda motiflere, nakislara ve kabartmalara yer verilmistir ve bu Turk resim sanati da degistiren bir degisim olmustur."),
-                                  vec!("Yabanci sanatcilar Istanbul’a gelerek eser vermisler, kendi bildiklerini yaymaya calismislardir. Bircoguna gore bu sanat alanindaki batililasma icin oncu niteliginde bir olaydir. Bana soracak olursan eger zaten Turk Sanati o donem Avrupa’dan cok etkilenmis bir noktadaydi, onlar ise oncu degil de degisimi hizlandiran etkenler olmuslardir."),
-                                  vec!("Haydi selametle.")
-                             ),
+    spawner::build_npc_human(gs, "npc.ali.name", Place::OTTOMAN_TOP, (OTTOMAN_TOP_X + OTTOMAN_TOP_WIDTH / 2 + 1, OTTOMAN_TOP_Y + OTTOMAN_TOP_HEIGHT / 2),
+                             vec!(vec!("npc.ali.0.0"),
+                                  vec!("npc.ali.1.0"),
+                                  vec!("npc.ali.2.0"),
+                                  vec!("npc.ali.3.0"),
+                                  vec!("npc.ali.4.0"),
+                                  vec!("npc.ali.5.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardMosquePart1)),
                              vec!(),
                              vec!(4),
                              vec!(4));
 
-    spawner::build_npc_human(&mut gs, "Emine Hanim", Place::OttomanTop, (OTTOMAN_TOP_X + OTTOMAN_TOP_WIDTH / 2 - 1, OTTOMAN_TOP_Y + OTTOMAN_TOP_HEIGHT / 2),
-                             vec!(vec!("17. yuzyilin sonlarinda Lale Devri adinda bir uslup dogmustur. Kullanilan sade desenler, yerini daha karmasik desenlere birakmistir ve Barok uslubu kullanilmaya baslanmistir. Bunun devaminda Rokoko uslubuna gecilmis, daha hafif desenler kullanilmaya devam edilmistir. Barok ve Rokoko uslubunun devaminda ise Ampir uslubuna gecilmistir. Ampir uslubun en onemli orneklerinden biri ise Nusretiye Camii’dir ve 1826’da yapilmistir. Osmanli’nin son donemlerinde Bati ile, ozellikle de Fransa ile, etkilesimlerin artmasi sanati bircok alanda etkilemistir ve mimari de kuskusuz bu alanlardan biridir."),
-                                  vec!("18. Yuzyilda baslayan ve 19. Yuzyil boyunca devam eden bu etkilenme donemine “Batililasma Hareketi” denmistir. Yurt disindan gelen yabanci mimarlarin Osmanli’da yapi faaliyetlerinin bir parcasi olmalari, batililasmayi hizlandirmistir. Avrupa’da ulusculuk dusuncesinin onem kazanmasi sonucunda gecmise duyulan hayranlik ve ozlem artmis, sonucunda Neo-Klasik uslup dogmustur. Bu uslup cercevesinde cephe duzenlemelerinde gecmise dayanan teknikler kullanilmistir."),
-                                  vec!("Biraz once bahsetmis oldugum sebeplerden mutevellit, Osmanli mimarisinde ozellikle cephe yapilarinda da benzer degisimler gorulmustur. Yabanci mimarlar ozellikle anitsal yapilar uzerine calismislardir. 1890 yilinda tamamlanan Istanbul Sirkeci Gari, Osmanli ve Alman Mimari’lerinin kaynastigi bir yapidir ve gecis doneminin bir parcasi olarak kabul edilir. 19. Yuzyilin sonu ve 20. Yuzyilin baslarinda gelisen Turkculuk anlayisi isiginda mimarlik sanati ulusal olma yolunda ilerlemistir."),
-                                  vec!("Bunun sebebi olarak batili yontemlerden uzaklasilmistir. Turkculuk anlayisi, 2. Mesrutiyet’in ilani ile daha da guclenmis ve 1930’lu yillara kadar varligini surdurmustur. Bu anlayis sayesinde batili yontemlerden farkli, milli ve ulusal bir mimari uslup icerisinde eserler verilmistir. Donemlerin degismesi ile birlikte toplumun yeni ihtiyaclari ortaya cikmistir ve bu ihtiyaclari karsilamak adina yeni yapilar yapilmaya baslanmistir. Bu yapilara bankalar, hastaneler, muzeler ornek gosterilebilir."),
-                                  vec!("Cephe duzenlemeleri Ronesans \
-                                  yapilarina benzeyecek sekilde on cephenin gosterisli olacak sekilde, diger cepheler daha sade kalacak sekilde ayarlanmistir. Dis gorunus konusunda degisimler yasanmasina karsin ic duzenlemeler konusunda pek bir degisim olmamistir. Bunun sebebi donemin getirmis oldugu bir bakis acisi olan, fonksiyonel bir amac gutmeden, mimariyi daha cok bir sanat olarak gormektir."),
-                                  vec!("Kalin saglicagla.")
-                             ),
+    spawner::build_npc_human(gs, "npc.emine.name", Place::OTTOMAN_TOP, (OTTOMAN_TOP_X + OTTOMAN_TOP_WIDTH / 2 - 1, OTTOMAN_TOP_Y + OTTOMAN_TOP_HEIGHT / 2),
+                             vec!(vec!("npc.emine.0.0"),
+                                  vec!("npc.emine.1.0"),
+                                  vec!("npc.emine.2.0"),
+                                  vec!("npc.emine.3.0"),
+                                  vec!("npc.emine.4.0"),
+                                  vec!("npc.emine.5.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardMosquePart2)),
                              vec!(),
@@ -428,32 +622,29 @@ fn main() -> rltk::BError {
                              vec!(4));
 
 
-    spawner::build_npc_human(&mut gs, "Ahmet Bey", Place::OttomanRight, (OTTOMAN_RIGHT_X + OTTOMAN_RIGHT_WIDTH / 2 + 2, OTTOMAN_RIGHT_Y + OTTOMAN_RIGHT_HEIGHT / 2),
-                             vec!(vec!("Klasik bati muzigine olan hayranligin artmasi sonucu, Osmanli muzigi bir baskalasim gecirmistir. Bunun arkasindaki en onemli etkenlerden biri de yabanci muzik ustatlarinin Osmanli’da verdigi konserlerdir. Buna ornek olarak Franz Lizst’in Istanbul’da verdigi konserler verilebilir. Biraz enteresan gelebilir ancak o donemlerde Avrupa’da da Turk muziginden alintilar gormek mumkundur. Mozart ve Beethoven’in kullanmis olduklari ritimler ve melodiler mehter muziginin ozelliklerini bunyesinde barindirmaktadirlar."),
-                                  vec!("Padisahlara eserler hediye edilmesi de sikca gozlenen bir durumdur ve Avrupa muzigi ile kurulan bu yakinlik, bati muzigine olan ilgiyi arttirmis ve iki muzik kulturune de katkilarda bulunmustur."),
-                                  vec!("Hayirli gunler.")
-                             ),
+    spawner::build_npc_human(gs, "npc.ahmet.name", Place::OTTOMAN_RIGHT, (OTTOMAN_RIGHT_X + OTTOMAN_RIGHT_WIDTH / 2 + 2, OTTOMAN_RIGHT_Y + OTTOMAN_RIGHT_HEIGHT / 2),
+                             vec!(vec!("npc.ahmet.0.0"),
+                                  vec!("npc.ahmet.1.0"),
+                                  vec!("npc.ahmet.2.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardNotePaper)),
                              vec!(),
                              vec!(1),
                              vec!(1));
-    spawner::build_npc_human(&mut gs, "Nefise Hanim", Place::OttomanRight, (OTTOMAN_RIGHT_X + OTTOMAN_RIGHT_WIDTH / 2, OTTOMAN_RIGHT_Y + OTTOMAN_RIGHT_HEIGHT / 2 - 1),
-                             vec!(vec!("19. yuzyil sonlarina dogru Osmanli’da batili sayilabilecek ilk tuval resimleri ortaya cikmistir. Bilindigi uzere Osmanli padisahlari siyasi cokusun onune gecebilmek adina batili teknolojiyi benimsemislerdir. Dogal olarak bu benimseyis bircok alanda degisimlere yol acmistir. Simdi enteresan ve arasinda baglanti olmasi beklenmeyen iki durumdan bahsedecegim. Askeri gucu arttirabilmek adina batili egitimi oncu kabul eden askeri okullar acildi. Acilan bu askeri okullarda da resim dersi mevcuttu."),
-                                  vec!("Bu ders ilk baslarda teknik bir ders olarak verilmekteydi. Bunun sonucunda da batiyi ornek alan Turk resimleri ortaya cikmaya basladi. Zaman icinde teknik olan bu ders sanatsal bir deger de kazanmaya basladi. Batidaki tekniklerin ogrenimi daha ileri bir seviyeye tasimak amaciyla, bu teknikleri yerinde ogrenmeleri icin Avrupa’ya ogrenci gruplari gonderilmistir. Ilk grup 1829’da gonderilmis, devami 1834, 1835, 1846 seklinde devam etmistir. Gidilen Avrupa ulkeleri ise Ingiltere, Fransa ve Avusturya olmustur."),
-                                  vec!("Ileriki senelerde, sanat egitimini Avrupa’da almalari icin de ogrenci gonderimi devam etmistir. Bu sekilde egitim goren ve Turk resmine buyuk katkilari bulunan ressamlara Halil Pasa, Sami Yetik, Ibrahim Calli verilebilir. 1883 yilinda Sanayi-i Nefise Mekteb-i Alisi adinda bir guzel sanatlar akademisi kurulmus ve resim egitimini akademik bir disiplin ile yurutecek bir birim olusturulmustur. Asker ressamlar arasinda ogretmen-ogrenci iliskileri kurulmustur. Her kusak kendilerinden once gelenlerin biraktiklari mirasa eklemeler yaparak eser vermeye devam etmislerdir."),
-                                  vec!("Iyi gunler dilerim.")
-                             ),
+    spawner::build_npc_human(gs, "npc.nefise.name", Place::OTTOMAN_RIGHT, (OTTOMAN_RIGHT_X + OTTOMAN_RIGHT_WIDTH / 2, OTTOMAN_RIGHT_Y + OTTOMAN_RIGHT_HEIGHT / 2 - 1),
+                             vec!(vec!("npc.nefise.0.0"),
+                                  vec!("npc.nefise.1.0"),
+                                  vec!("npc.nefise.2.0"),
+                                  vec!("npc.nefise.3.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardCanvas)),
                              vec!(),
                              vec!(2),
                              vec!(2));
-    spawner::build_npc_human(&mut gs, "Almila Hanim", Place::OttomanRight, (OTTOMAN_RIGHT_X + OTTOMAN_RIGHT_WIDTH / 2 + 1, OTTOMAN_RIGHT_Y + OTTOMAN_RIGHT_HEIGHT / 2 + 1),
-                             vec!(vec!("Heykel uretimi Osmanli topraklarina 19.yuzyilin sonlarina dogru varmistir. Bunun en buyuk sebebi, toplumun geleneksel degerlerinden uzaklasmak istememesidir. 1871 yilinda, Sultan Abdulaziz’in Avrupa kentlerine yapmis oldugu gezi donusunde kendi heykelini yaptirmistir ve bu olay heykel sanatinin kesin olarak Osmanli sanatinin bir parcasi haline gelmesini saglamistir. 1883 yilinda Sanayi-i Nefise Mekteb-i Alisi’nin acilmasinin ardindan Osmanli’da heykeltiras yetistirecek bir okul ilk kez acilmis olur."),
-                                  vec!("Simdi izninle Yervant Osgan Efendi’yi yad etmek istiyorum. Kendisi Avrupa’da heykel uzerine ogrenim goren ilk Osmanli genci olmasi ile bilinir. Sanayi-i Nefise’de 32 yil boyunca ogretim uyesi olarak calisir, bircok sanatcinin yetismesinde rol oynar. Ayni zamanda kendisi de gercekci yorumlarini kattigi bir suru heykel uretmistir."),
-                                  vec!("Gorusmek uzere.")
-                             ),
+    spawner::build_npc_human(gs, "npc.almila.name", Place::OTTOMAN_RIGHT, (OTTOMAN_RIGHT_X + OTTOMAN_RIGHT_WIDTH / 2 + 1, OTTOMAN_RIGHT_Y + OTTOMAN_RIGHT_HEIGHT / 2 + 1),
+                             vec!(vec!("npc.almila.0.0"),
+                                  vec!("npc.almila.1.0"),
+                                  vec!("npc.almila.2.0")),
                              None,
                              Some(vec!(ItemName::OttomanRewardClay)),
                              vec!(),
@@ -461,31 +652,110 @@ fn main() -> rltk::BError {
                              vec!(1));
 
 
-    spawner::build_npc_human_one_liner(&mut gs, "Efe", Place::SchoolSouth, (17, 14), "Merhaba.");
-    spawner::build_npc_human_one_liner(&mut gs, "Aysila", Place::SchoolSouth, (32, 23), "Selamlar!");
+    spawner::build_npc_human_one_liner(gs, "npc.hasan.name", Place::OTTOMAN_BOTTOM, (OTTOMAN_BOTTOM_X + OTTOMAN_BOTTOM_WIDTH / 2, OTTOMAN_BOTTOM_Y + OTTOMAN_BOTTOM_HEIGHT / 2),
+                                       "npc.hasan.0.0");
 
-    spawner::build_npc_dog(&mut gs, "Karbeyaz", Place::SchoolNorth, (31, 22), RGB::from_u8(10, 10, 10), "HAV HAV");
-    spawner::build_npc_dog(&mut gs, "Naci", Place::SchoolNorth, (17, 15), RGB::from_u8(230, 230, 132), "Hav");
-    spawner::build_npc_dog(&mut gs, "Pasa", Place::SchoolNorth, (16, 22), RGB::from_u8(30, 30, 30), "Hav Hav");
-    spawner::build_npc_cat(&mut gs, "Adolf", Place::SchoolNorth, (31, 25), RGB::from_u8(229, 229, 201), "Mrrnav");
-    spawner::build_npc_cat(&mut gs, "Deli", Place::SchoolNorth, (34, 24), RGB::from_u8(228, 228, 49), "Miav");
+    spawner::build_npc_human_patrolling(gs, "npc.efe.name", Place::SCHOOL_SOUTH, vec!((17, 14), (22, 14), (22, 18), (17, 18)), "npc.efe.0.0");
+    spawner::build_npc_human_patrolling(gs, "npc.aysila.name", Place::SCHOOL_SOUTH, vec!((32, 23), (32, 27), (27, 27), (27, 23)), "npc.aysila.0.0");
 
+    spawner::build_npc_dog(gs, "npc.karbeyaz.name", Place::SCHOOL_NORTH, (31, 22), RGB::from_u8(10, 10, 10), "npc.karbeyaz.0.0");
+    spawner::build_npc_dog(gs, "npc.naci.name", Place::SCHOOL_NORTH, (17, 15), RGB::from_u8(230, 230, 132), "npc.naci.0.0");
+    spawner::build_npc_dog(gs, "npc.pasa.name", Place::SCHOOL_NORTH, (16, 22), RGB::from_u8(30, 30, 30), "npc.pasa.0.0");
+    spawner::build_npc_cat(gs, "npc.adolf.name", Place::SCHOOL_NORTH, (31, 25), RGB::from_u8(229, 229, 201), "npc.adolf.0.0");
+    spawner::build_npc_cat(gs, "npc.deli.name", Place::SCHOOL_NORTH, (34, 24), RGB::from_u8(228, 228, 49), "npc.deli.0.0");
 
-    let map = Map::new_map_rooms_and_corridors(&mut gs.ecs, Place::Home);
+    spawner::arrange_generated_places(gs);
+
+    let map = Map::new_map_rooms_and_corridors(&mut gs.ecs, Place::HOME);
     gs.ecs.insert(map);
     gs.ecs.insert(log);
     gs.ecs.insert(player_entity);
     gs.ecs.insert(Point::new(player_coord.0, player_coord.1));
     gs.ecs.insert(TargetedPosition { x: -1, y: -1 });
-    gs.ecs.insert(RunState::Menu { menu_selection: MainMenuSelection::NewGame });
+    gs.ecs.insert(AutoWalk { path: Vec::new(), revealed: 0 });
+    gs.ecs.insert(PlayerTurn { acted: false });
     gs.ecs.insert(Objective {
-        objectives: vec!("Sinifa git ve Taylan Hoca ile konus".to_string(), "Taylan Hoca'nin kitaplarini bul ve derse getir".to_string(), "Gizli gecidi bul ve arastir".to_string(),
-                         "Ilk dersi dinle".to_string(), "Ikınci dersi dinle".to_string(), "Ucuncu dersi dinle".to_string(), "Gizemli karakter ile tekrardan konus".to_string(),
-                         "Dorduncu dersi dinle".to_string(), "Besinci dersi dinle".to_string(), "Gizemli karakter ile tekrardan konus".to_string(),
-                         "Altinci dersi dinle".to_string(), "Yedinci dersi dinle".to_string(), "Sekizinci dersi dinle".to_string(), "Gizemli karakter ile tekrardan konus".to_string(),
-                         "Kendi zaman dilimine don".to_string()),
+        objectives: vec!("objective.talk_to_taylan".to_string(), "objective.find_books".to_string(), "objective.secret_passage".to_string(),
+                         "objective.lecture_1".to_string(), "objective.lecture_2".to_string(), "objective.lecture_3".to_string(), "objective.return_to_stranger".to_string(),
+                         "objective.lecture_4".to_string(), "objective.lecture_5".to_string(), "objective.return_to_stranger".to_string(),
+                         "objective.lecture_6".to_string(), "objective.lecture_7".to_string(), "objective.lecture_8".to_string(), "objective.return_to_stranger".to_string(),
+                         "objective.go_home".to_string()),
         index: 0,
     });
 
+}
+
+rltk::embedded_resource!(FONT, "../resources/turkish8x8.png");
+
+fn main() -> rltk::BError {
+    use rltk::RltkBuilder;
+    rltk::link_resource!(FONT, format!("resources/{}", FONT_FILE));
+    let settings = Settings::load();
+    if let Some(language) = language_from_args().or(settings.language()) {
+        i18n::set_language(language);
+    }
+    let builder = RltkBuilder::new()
+        .with_dimensions(SCREEN_WIDTH, SCREEN_HEIGHT)
+        .with_title(tr(WINDOW_TITLE_STR))
+        .with_tile_dimensions(settings.tile_pixels, settings.tile_pixels)
+        .with_font(FONT_FILE, FONT_TILE_SIZE, FONT_TILE_SIZE)
+        .with_simple_console(SCREEN_WIDTH, SCREEN_HEIGHT, FONT_FILE)
+        .with_advanced_input(true);
+    #[cfg(feature = "opengl")]
+    let builder = builder.with_automatic_console_resize(true);
+    let context = builder.build()?;
+    let mut gs = State {
+        ecs: World::new(),
+        dispatcher: dispatcher::build_game_dispatcher(),
+        event_dispatcher: dispatcher::build_event_dispatcher(),
+    };
+
+    gs.ecs.register::<Position>();
+    gs.ecs.register::<TargetedPosition>();
+    gs.ecs.register::<Renderable>();
+    gs.ecs.register::<Player>();
+    gs.ecs.register::<Name>();
+    gs.ecs.register::<Item>();
+    gs.ecs.register::<Stored>();
+    gs.ecs.register::<Impassable>();
+    gs.ecs.register::<RequiresItem>();
+    gs.ecs.register::<ContainsItem>();
+    gs.ecs.register::<RequiresItems>();
+    gs.ecs.register::<ContainsItems>();
+    gs.ecs.register::<PermanentItem>();
+    gs.ecs.register::<SimpleMarker<SerializeMe>>();
+    gs.ecs.register::<SerializationHelper>();
+    gs.ecs.register::<Portal>();
+    gs.ecs.register::<BelongsTo>();
+    gs.ecs.register::<Npc>();
+    gs.ecs.register::<Objective>();
+    gs.ecs.register::<Interaction>();
+    gs.ecs.register::<DormantPosition>();
+    gs.ecs.register::<Trigger>();
+    gs.ecs.register::<PlayerName>();
+    gs.ecs.register::<Movement>();
+    gs.ecs.register::<Befriendable>();
+    gs.ecs.register::<Companion>();
+    gs.dispatcher.setup(&mut gs.ecs);
+    gs.event_dispatcher.setup(&mut gs.ecs);
+
+    gs.ecs.insert(SimpleMarkerAllocator::<SerializeMe>::new());
+    gs.ecs.insert(map_generation_from_args());
+    gs.ecs.insert(GameEvents::default());
+    gs.ecs.insert(KeyBindings::load());
+    gs.ecs.insert(hints::Hints::default());
+    gs.ecs.insert(achievements::AchievementToasts::default());
+    gs.ecs.insert(ScreenSize::new(settings.tile_pixels));
+    gs.ecs.insert(Layout::default());
+    gs.ecs.insert(palette::EraTransition::new(settings.era_transition && !std::env::args().any(|arg| arg == "--no-era-transition")));
+    gs.ecs.insert(settings);
+    let profiles = Profiles::load();
+    gs.ecs.insert(match profiles.profiles.is_empty() {
+        true => RunState::EnterName,
+        false => RunState::SelectProfile { selection: 0 },
+    });
+    gs.ecs.insert(profiles);
+    populate_new_game(&mut gs);
+
     rltk::main_loop(context, gs)
 }
\ No newline at end of file
@@ -1,10 +1,12 @@
-use rltk::Rltk;
+use rltk::{Algorithm2D, BaseMap, DistanceAlg, Point, RandomNumberGenerator, Rltk, SmallVec};
 use serde::{Deserialize, Serialize};
 use specs::{Join, World, WorldExt};
-use crate::constants::{BACKGROUND_COLOR, CLASS_HEIGHT, CLASS_WIDTH, CLASS_X, CLASS_Y, CURRENT_DATE, HOME_HEIGHT, HOME_WIDTH, HOME_X, HOME_Y, LIBRARY_HEIGHT, LIBRARY_WIDTH, LIBRARY_X, LIBRARY_Y, MAP_HEIGHT, MAP_TILES, MAP_WIDTH, OTTOMAN_BOTTOM_HEIGHT, OTTOMAN_BOTTOM_WIDTH, OTTOMAN_BOTTOM_X, OTTOMAN_BOTTOM_Y, OTTOMAN_LEFT_HEIGHT, OTTOMAN_LEFT_WIDTH, OTTOMAN_LEFT_X, OTTOMAN_LEFT_Y, OTTOMAN_MAIN_HEIGHT, OTTOMAN_MAIN_WIDTH, OTTOMAN_MAIN_X, OTTOMAN_MAIN_Y, OTTOMAN_RIGHT_HEIGHT, OTTOMAN_RIGHT_WIDTH, OTTOMAN_RIGHT_X, OTTOMAN_RIGHT_Y, OTTOMAN_TOP_HEIGHT, OTTOMAN_TOP_WIDTH, OTTOMAN_TOP_X, OTTOMAN_TOP_Y, PAST_DATE, PLACE_CLASS_NAME, PLACE_HOME_NAME, PLACE_LIB_NAME, PLACE_OTTOMAN_LEFT_NAME, PLACE_OTTOMAN_MAIN_NAME, PLACE_OTTOMAN_RIGHT_NAME, PLACE_OTTOMAN_TOP_NAME, PLACE_SCHOOL_NORTH_NAME, PLACE_SCHOOL_SOUTH_NAME, SCHOOL_NORTH_HEIGHT, SCHOOL_NORTH_WIDTH, SCHOOL_NORTH_X, SCHOOL_NORTH_Y, SCHOOL_SOUTH_HEIGHT, SCHOOL_SOUTH_WIDTH, SCHOOL_SOUTH_X, SCHOOL_SOUTH_Y, SPACE_COLOR, TILE_COLOR, WALL_COLOR};
-use super::{BelongsTo, Npc, Portal, Position, Rect, RequiresItem};
+use crate::constants::{BACKGROUND_COLOR, GENERATED_AREA_HEIGHT, GENERATED_AREA_WIDTH, GENERATED_AREA_X, GENERATED_AREA_Y, GENERATED_MAX_ROOMS, GENERATED_ROOM_MAX_SIZE, GENERATED_ROOM_MIN_SIZE, MAP_HEIGHT, MAP_TILES, MAP_WIDTH};
+use crate::glyphs::display_glyph;
+use crate::palette::current_palette;
+use super::{BelongsTo, Npc, Place, Portal, Position, Rect, RequiresItem};
 
-#[derive(PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum TileType {
     Space,
     Wall,
@@ -14,41 +16,22 @@ pub enum TileType {
     NPC,
 }
 
-#[derive(PartialEq, Copy, Clone, Serialize, Deserialize)]
-pub enum Place {
-    Home,
-    SchoolSouth,
-    SchoolNorth,
-    Class,
-    Library,
-    OttomanMain,
-    OttomanLeft,
-    OttomanRight,
-    OttomanTop,
+/// Seed of the run, every generated place derives its own layout from it
+#[derive(PartialEq, Copy, Clone, Default, Serialize, Deserialize)]
+pub struct MapGeneration {
+    pub seed: u64,
+    pub enabled: bool,
 }
 
-impl Place {
-    pub fn get_name(&self) -> String {
-        String::from(match self {
-            Place::Home => PLACE_HOME_NAME,
-            Place::SchoolSouth => PLACE_SCHOOL_SOUTH_NAME,
-            Place::SchoolNorth => PLACE_SCHOOL_NORTH_NAME,
-            Place::Class => PLACE_CLASS_NAME,
-            Place::Library => PLACE_LIB_NAME,
-            Place::OttomanMain => PLACE_OTTOMAN_MAIN_NAME,
-            Place::OttomanLeft => PLACE_OTTOMAN_LEFT_NAME,
-            Place::OttomanRight => PLACE_OTTOMAN_RIGHT_NAME,
-            Place::OttomanTop => PLACE_OTTOMAN_TOP_NAME,
-        })
-    }
-    pub fn get_year(&self) -> String {
-        String::from(match self {
-            Place::SchoolSouth | Place::SchoolNorth | Place::Home | Place::Class | Place::Library => CURRENT_DATE,
-            Place::OttomanMain | Place::OttomanLeft | Place::OttomanRight | Place::OttomanTop => PAST_DATE,
-        })
+impl MapGeneration {
+    pub fn rng_for(&self, place: Place) -> RandomNumberGenerator {
+        RandomNumberGenerator::seeded(self.seed.wrapping_add((place.index() as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15)))
     }
 }
 
+/// A portal tile in the wall of a generated room and the tile where the player arrives through it
+pub type PortalSpot = ((i32, i32), (i32, i32));
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Map {
     pub tiles: Vec<TileType>,
@@ -105,25 +88,126 @@ impl Map {
             self.tiles[Map::xy_to_tile(x, y)] = TileType::Wall;
         }
     }
-    pub fn new_map_rooms_and_corridors(ecs: &mut World, place: Place) -> Map {
+
+    fn apply_floor_to_map(&mut self, room: &Rect) {
+        for y in room.y1..room.y2 {
+            for x in room.x1..room.x2 {
+                self.tiles[Map::xy_to_tile(x, y)] = TileType::Floor;
+            }
+        }
+    }
+
+    fn apply_horizontal_corridor(&mut self, x1: i32, x2: i32, y: i32) {
+        for x in x1.min(x2)..=x1.max(x2) {
+            self.tiles[Map::xy_to_tile(x, y)] = TileType::Floor;
+        }
+    }
+
+    fn apply_vertical_corridor(&mut self, y1: i32, y2: i32, x: i32) {
+        for y in y1.min(y2)..=y1.max(y2) {
+            self.tiles[Map::xy_to_tile(x, y)] = TileType::Floor;
+        }
+    }
+
+    /// Surrounds every floor tile with walls, so corridors get walls like the rooms do
+    fn apply_walls_around_floor(&mut self) {
+        for y in 1..self.height - 1 {
+            for x in 1..self.width - 1 {
+                if self.tiles[Map::xy_to_tile(x, y)] != TileType::Space { continue; }
+                let next_to_floor = (-1..=1).any(|dy| (-1..=1).any(|dx| self.tiles[Map::xy_to_tile(x + dx, y + dy)] == TileType::Floor));
+                if next_to_floor {
+                    self.tiles[Map::xy_to_tile(x, y)] = TileType::Wall;
+                }
+            }
+        }
+    }
+
+    /// Carves randomly placed rooms connected by corridors, in the order they were created
+    fn apply_generated_streets(&mut self, rng: &mut RandomNumberGenerator) -> Vec<Rect> {
+        let mut rooms: Vec<Rect> = Vec::new();
+        for _ in 0..GENERATED_MAX_ROOMS {
+            let w = rng.range(GENERATED_ROOM_MIN_SIZE, GENERATED_ROOM_MAX_SIZE + 1);
+            let h = rng.range(GENERATED_ROOM_MIN_SIZE, GENERATED_ROOM_MAX_SIZE + 1);
+            let x = rng.range(GENERATED_AREA_X, GENERATED_AREA_X + GENERATED_AREA_WIDTH - w);
+            let y = rng.range(GENERATED_AREA_Y, GENERATED_AREA_Y + GENERATED_AREA_HEIGHT - h);
+            let room = Rect::new(x, y, w, h);
+            if rooms.iter().any(|other| room.intersect(other)) { continue; }
+            self.apply_floor_to_map(&room);
+            if let Some(previous) = rooms.last() {
+                let (new_x, new_y) = room.center;
+                let (prev_x, prev_y) = previous.center;
+                if rng.range(0, 2) == 1 {
+                    self.apply_horizontal_corridor(prev_x, new_x, prev_y);
+                    self.apply_vertical_corridor(prev_y, new_y, new_x);
+                } else {
+                    self.apply_vertical_corridor(prev_y, new_y, prev_x);
+                    self.apply_horizontal_corridor(prev_x, new_x, new_y);
+                }
+            }
+            rooms.push(room);
+        }
+        self.apply_walls_around_floor();
+        rooms
+    }
+
+    /// Builds the tiles of a place without any entities on them, the rooms are only
+    /// returned for generated places
+    pub fn build_layout(place: Place, generation: &MapGeneration) -> (Map, Vec<Rect>, RandomNumberGenerator) {
         let mut map = Map {
             tiles: vec![TileType::Space; MAP_TILES as usize],
             width: MAP_WIDTH,
             height: MAP_HEIGHT,
             place,
         };
-        let created_place: Rect = match place {
-            Place::Home => Rect::new(HOME_X, HOME_Y, HOME_WIDTH, HOME_HEIGHT),
-            Place::SchoolSouth => Rect::new(SCHOOL_SOUTH_X, SCHOOL_SOUTH_Y, SCHOOL_SOUTH_WIDTH, SCHOOL_SOUTH_HEIGHT),
-            Place::SchoolNorth => Rect::new(SCHOOL_NORTH_X, SCHOOL_NORTH_Y, SCHOOL_NORTH_WIDTH, SCHOOL_NORTH_HEIGHT),
-            Place::Class => Rect::new(CLASS_X, CLASS_Y, CLASS_WIDTH, CLASS_HEIGHT),
-            Place::Library => Rect::new(LIBRARY_X, LIBRARY_Y, LIBRARY_WIDTH, LIBRARY_HEIGHT),
-            Place::OttomanMain => Rect::new(OTTOMAN_MAIN_X, OTTOMAN_MAIN_Y, OTTOMAN_MAIN_WIDTH, OTTOMAN_MAIN_HEIGHT),
-            Place::OttomanLeft => Rect::new(OTTOMAN_LEFT_X, OTTOMAN_LEFT_Y, OTTOMAN_LEFT_WIDTH, OTTOMAN_LEFT_HEIGHT),
-            Place::OttomanRight => Rect::new(OTTOMAN_RIGHT_X, OTTOMAN_RIGHT_Y, OTTOMAN_RIGHT_WIDTH, OTTOMAN_RIGHT_HEIGHT),
-            Place::OttomanTop => Rect::new(OTTOMAN_TOP_X, OTTOMAN_TOP_Y, OTTOMAN_TOP_WIDTH, OTTOMAN_TOP_HEIGHT),
-        };
+        let mut rng = generation.rng_for(place);
+        if generation.enabled && place.is_generated() {
+            let rooms = map.apply_generated_streets(&mut rng);
+            return (map, rooms, rng);
+        }
+        let created_place: Rect = place.get_bounds();
         map.apply_room_to_map(&created_place);
+        (map, vec!(), rng)
+    }
+
+    /// Where the portals of a generated place stand in the top or bottom walls of its rooms, each
+    /// with the tile inside the room where the player arrives through it; the portals go to
+    /// different rooms first and share a room only when there are more portals than rooms
+    pub fn portal_tiles(rooms: &[Rect], count: usize) -> Vec<PortalSpot> {
+        let spots: Vec<Vec<PortalSpot>> = rooms.iter().map(|room| {
+            let mut spots = Vec::new();
+            for (wall_y, arrival_y) in [(room.y1 - 1, room.y1), (room.y2, room.y2 - 1)] {
+                for offset in [0, -2, 2, -4, 4] {
+                    let x = room.center.0 + offset;
+                    if x >= room.x1 && x < room.x2 { spots.push(((x, wall_y), (x, arrival_y))); }
+                }
+            }
+            spots
+        }).collect();
+        (0..count).map(|index| {
+            let room = &spots[index % spots.len()];
+            room[index / spots.len() % room.len()]
+        }).collect()
+    }
+
+    /// Floor tiles along the inner edges of the rooms, the NPCs of a generated place stand there
+    pub fn tiles_along_room_edges(&self, rooms: &[Rect]) -> Vec<(i32, i32)> {
+        let mut tiles: Vec<(i32, i32)> = Vec::new();
+        for room in rooms.iter() {
+            for y in room.y1 + 1..room.y2 - 1 {
+                for x in room.x1 + 1..room.x2 - 1 {
+                    let next_to_edge = x == room.x1 + 1 || x == room.x2 - 2 || y == room.y1 + 1 || y == room.y2 - 2;
+                    if next_to_edge && self.tiles[Map::xy_to_tile(x, y)] == TileType::Floor {
+                        tiles.push((x, y));
+                    }
+                }
+            }
+        }
+        tiles
+    }
+
+    pub fn new_map_rooms_and_corridors(ecs: &mut World, place: Place) -> Map {
+        let generation = *ecs.fetch::<MapGeneration>();
+        let (mut map, _, _) = Map::build_layout(place, &generation);
 
         map.adjust_tiles(ecs);
 
@@ -131,6 +215,36 @@ impl Map {
     }
 }
 
+impl Algorithm2D for Map {
+    fn dimensions(&self) -> Point {
+        Point::new(self.width, self.height)
+    }
+}
+
+impl BaseMap for Map {
+    /// Only floor tiles can be walked through, anything else has to be bumped into
+    fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+        let mut exits = SmallVec::new();
+        let x = idx as i32 % self.width;
+        let y = idx as i32 / self.width;
+        for (delta_x, delta_y) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (new_x, new_y) = (x + delta_x, y + delta_y);
+            if new_x < 0 || new_x >= self.width || new_y < 0 || new_y >= self.height { continue; }
+            let new_idx = Map::xy_to_tile(new_x, new_y);
+            if self.tiles[new_idx] == TileType::Floor {
+                exits.push((new_idx, 1.0));
+            }
+        }
+        exits
+    }
+
+    fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+        let p1 = self.index_to_point2d(idx1);
+        let p2 = self.index_to_point2d(idx2);
+        DistanceAlg::Manhattan.distance2d(p1, p2)
+    }
+}
+
 fn is_wall(map: &Map, x: i32, y: i32) -> bool {
     map.tiles[Map::xy_to_tile(x, y)] == TileType::Wall
 }
@@ -167,6 +281,7 @@ fn wall_glyph(map: &Map, x: i32, y: i32) -> rltk::FontCharType {
 
 pub fn draw_map(ecs: &World, ctx: &mut Rltk) {
     let map = ecs.fetch::<Map>();
+    let palette = current_palette(ecs);
 
     let mut x: i32 = 0;
     let mut y: i32 = 0;
@@ -176,17 +291,17 @@ pub fn draw_map(ecs: &World, ctx: &mut Rltk) {
         let fg;
         match tile {
             TileType::Wall => {
-                glyph = wall_glyph(&*map, x, y);
-                fg = WALL_COLOR;
+                glyph = display_glyph(wall_glyph(&*map, x, y));
+                fg = palette.wall;
                 ctx.set(x, y, fg, BACKGROUND_COLOR, glyph);
             }
             TileType::Floor => {
                 glyph = rltk::to_cp437('.');
-                fg = TILE_COLOR;
+                fg = palette.tile;
                 ctx.set(x, y, fg, BACKGROUND_COLOR, glyph);
             }
             TileType::Space => {
-                ctx.set_bg(x, y, SPACE_COLOR);
+                ctx.set_bg(x, y, palette.space);
             }
             _ => {}
         }
@@ -198,3 +313,84 @@ pub fn draw_map(ecs: &World, ctx: &mut Rltk) {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use rltk::BaseMap;
+    use super::{Map, MapGeneration, Rect, TileType};
+    use crate::Place;
+
+    const SEEDS: [u64; 4] = [0, 1, 1923, u64::MAX];
+
+    /// More portals than any generated place has, so that rooms get shared too
+    const PORTAL_COUNT: usize = 6;
+
+    fn generated_places() -> Vec<Place> {
+        Place::all().filter(|place| place.is_generated()).collect()
+    }
+
+    /// Every floor tile that can be walked to from the given one
+    fn reachable_from(map: &Map, start: (i32, i32)) -> HashSet<usize> {
+        let mut reached = HashSet::from([Map::xy_to_tile(start.0, start.1)]);
+        let mut frontier = vec![Map::xy_to_tile(start.0, start.1)];
+        while let Some(idx) = frontier.pop() {
+            for (next, _) in map.get_available_exits(idx) {
+                if reached.insert(next) { frontier.push(next); }
+            }
+        }
+        reached
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_layout() {
+        for seed in SEEDS {
+            let generation = MapGeneration { seed, enabled: true };
+            for place in generated_places() {
+                let (first, first_rooms, _) = Map::build_layout(place, &generation);
+                let (second, second_rooms, _) = Map::build_layout(place, &generation);
+                assert!(first.tiles == second.tiles, "tiles of place {} differ for seed {}", place.index(), seed);
+                let corners = |rooms: &[Rect]| rooms.iter().map(|room| (room.x1, room.y1, room.x2, room.y2)).collect::<Vec<_>>();
+                assert_eq!(corners(&first_rooms), corners(&second_rooms), "rooms of place {} differ for seed {}", place.index(), seed);
+            }
+        }
+    }
+
+    #[test]
+    fn npcs_stand_on_reachable_floor() {
+        for seed in SEEDS {
+            let generation = MapGeneration { seed, enabled: true };
+            for place in generated_places() {
+                let (map, rooms, _) = Map::build_layout(place, &generation);
+                assert!(!rooms.is_empty(), "no rooms in place {} for seed {}", place.index(), seed);
+                let reachable = reachable_from(&map, rooms[0].center);
+                for (x, y) in map.tiles_along_room_edges(&rooms) {
+                    assert_eq!(map.tiles[Map::xy_to_tile(x, y)], TileType::Floor);
+                    assert!(reachable.contains(&Map::xy_to_tile(x, y)), "NPC tile {:?} unreachable for seed {}", (x, y), seed);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_portal_gets_its_own_reachable_tile() {
+        for seed in SEEDS {
+            let generation = MapGeneration { seed, enabled: true };
+            for place in generated_places() {
+                let (map, rooms, _) = Map::build_layout(place, &generation);
+                let reachable = reachable_from(&map, rooms[0].center);
+                let tiles = Map::portal_tiles(&rooms, PORTAL_COUNT);
+                let npc_tiles = map.tiles_along_room_edges(&rooms);
+                assert_eq!(tiles.len(), PORTAL_COUNT);
+                for (index, (portal, arrival)) in tiles.iter().enumerate() {
+                    assert!(tiles[..index].iter().all(|(other, other_arrival)| other != portal && other_arrival != arrival),
+                            "portal {:?} of place {} shares a tile for seed {}", portal, place.index(), seed);
+                    assert_eq!((portal.0 - arrival.0).abs() + (portal.1 - arrival.1).abs(), 1);
+                    assert_eq!(map.tiles[Map::xy_to_tile(arrival.0, arrival.1)], TileType::Floor);
+                    assert!(reachable.contains(&Map::xy_to_tile(arrival.0, arrival.1)), "portal arrival {:?} unreachable for seed {}", arrival, seed);
+                    assert!(!npc_tiles.contains(arrival), "portal arrival {:?} can hold an NPC for seed {}", arrival, seed);
+                }
+            }
+        }
+    }
+}
@@ -6,4 +6,13 @@ pub enum NpcState {
     WantsItem,
     WillGiveItem,
     Done,
+}
+
+/// How an NPC gets around its place; NPCs without one, like the lecturers, stay put
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub enum MovementBehaviour {
+    /// Steps in a random direction now and then
+    Wander,
+    /// Walks through the route points in order, starting over after the last one
+    Patrol { route: Vec<(i32, i32)>, index: usize },
 }
\ No newline at end of file
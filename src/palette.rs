@@ -0,0 +1,174 @@
+use rltk::{Rltk, SimpleConsole, BACKEND_INTERNAL, RGB, RGBA};
+use serde::{Deserialize, Serialize};
+use specs::{World, WorldExt};
+use crate::constants::{COLOR_SCHEME_DEUTERANOPIA_STR, COLOR_SCHEME_PROTANOPIA_STR, COLOR_SCHEME_STANDARD_STR, COLOR_SCHEME_TRITANOPIA_STR, ERA_TRANSITION_FRAMES, HIGH_CONTRAST_BACKGROUND_SCALE, PAST_SEPIA_AMOUNT, PAST_SPACE_COLOR, PAST_TILE_COLOR, PAST_WALL_COLOR, SPACE_COLOR, TILE_COLOR, WALL_COLOR};
+use crate::i18n::tr;
+use crate::places::Era;
+use crate::settings::Settings;
+use crate::Place;
+
+/// Colours the map and the entities are drawn with
+#[derive(Clone, Copy)]
+pub struct Palette {
+    pub wall: RGB,
+    pub tile: RGB,
+    pub space: RGB,
+    /// How much entity colours are pulled towards sepia, 0 keeps them as they are
+    pub sepia: f32,
+}
+
+impl Palette {
+    pub fn for_era(era: Era) -> Palette {
+        match era {
+            Era::Present => Palette { wall: WALL_COLOR, tile: TILE_COLOR, space: SPACE_COLOR, sepia: 0.0 },
+            Era::Past => Palette { wall: PAST_WALL_COLOR, tile: PAST_TILE_COLOR, space: PAST_SPACE_COLOR, sepia: PAST_SEPIA_AMOUNT },
+        }
+    }
+
+    pub fn lerp(&self, other: &Palette, percent: f32) -> Palette {
+        Palette {
+            wall: self.wall.lerp(other.wall, percent),
+            tile: self.tile.lerp(other.tile, percent),
+            space: self.space.lerp(other.space, percent),
+            sepia: self.sepia + (other.sepia - self.sepia) * percent,
+        }
+    }
+
+    pub fn tint(&self, color: RGB) -> RGB {
+        if self.sepia <= 0.0 { return color; }
+        let sepia = RGB::from_f32(
+            (0.393 * color.r + 0.769 * color.g + 0.189 * color.b).min(1.0),
+            (0.349 * color.r + 0.686 * color.g + 0.168 * color.b).min(1.0),
+            (0.272 * color.r + 0.534 * color.g + 0.131 * color.b).min(1.0),
+        );
+        color.lerp(sepia, self.sepia)
+    }
+}
+
+/// Fades the palette from one era to the other after walking through a time portal
+pub struct EraTransition {
+    pub enabled: bool,
+    /// Era of the last place the player entered
+    pub era: Era,
+    pub from: Era,
+    pub remaining_frames: i32,
+}
+
+impl EraTransition {
+    pub fn new(enabled: bool) -> Self {
+        EraTransition { enabled, era: Era::Present, from: Era::Present, remaining_frames: 0 }
+    }
+
+    pub fn enter(&mut self, era: Era) {
+        if era == self.era { return; }
+        self.from = self.era;
+        self.era = era;
+        if self.enabled {
+            self.remaining_frames = ERA_TRANSITION_FRAMES;
+        }
+    }
+}
+
+/// Palette of the current place, blended with the previous era's while a transition runs
+pub fn current_palette(ecs: &World) -> Palette {
+    let era = ecs.fetch::<Place>().get_era();
+    let palette = Palette::for_era(era);
+    let transition = ecs.fetch::<EraTransition>();
+    if transition.remaining_frames <= 0 || transition.era != era {
+        return palette;
+    }
+    let percent = 1.0 - transition.remaining_frames as f32 / ERA_TRANSITION_FRAMES as f32;
+    Palette::for_era(transition.from).lerp(&palette, percent)
+}
+
+/// Called once per drawn frame, outside of transitions it keeps the era in sync with the place,
+/// which matters after loading a game
+pub fn advance_transition(ecs: &World) {
+    let mut transition = ecs.write_resource::<EraTransition>();
+    if transition.remaining_frames > 0 {
+        transition.remaining_frames -= 1;
+    } else {
+        transition.era = ecs.fetch::<Place>().get_era();
+    }
+}
+
+/// Colour vision the screen is adjusted for
+#[derive(Serialize, Deserialize, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ColorScheme {
+    Standard,
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+
+impl ColorScheme {
+    pub const ALL: [ColorScheme; 4] = [ColorScheme::Standard, ColorScheme::Protanopia, ColorScheme::Deuteranopia, ColorScheme::Tritanopia];
+
+    pub fn get_name(&self) -> String {
+        tr(match self {
+            ColorScheme::Standard => COLOR_SCHEME_STANDARD_STR,
+            ColorScheme::Protanopia => COLOR_SCHEME_PROTANOPIA_STR,
+            ColorScheme::Deuteranopia => COLOR_SCHEME_DEUTERANOPIA_STR,
+            ColorScheme::Tritanopia => COLOR_SCHEME_TRITANOPIA_STR,
+        })
+    }
+
+    pub fn next(&self) -> ColorScheme {
+        let index = ColorScheme::ALL.iter().position(|scheme| scheme == self).unwrap_or(0);
+        ColorScheme::ALL[(index + 1) % ColorScheme::ALL.len()]
+    }
+
+    /// Daltonizes the colour: the difference the viewer can't see is moved into the channels
+    /// they can, so red against green or blue against yellow still stand apart
+    pub fn adjust(&self, color: RGB) -> RGB {
+        if *self == ColorScheme::Standard { return color; }
+        let l = 17.8824 * color.r + 43.5161 * color.g + 4.11935 * color.b;
+        let m = 3.45565 * color.r + 27.1554 * color.g + 3.86714 * color.b;
+        let s = 0.0299566 * color.r + 0.184309 * color.g + 1.46709 * color.b;
+        let (l, m, s) = match self {
+            ColorScheme::Protanopia => (2.02344 * m - 2.52581 * s, m, s),
+            ColorScheme::Deuteranopia => (l, 0.494207 * l + 1.24827 * s, s),
+            _ => (l, m, -0.395913 * l + 0.801109 * m),
+        };
+        let seen_r = 0.080_944_45 * l - 0.130_504_4 * m + 0.116_721_07 * s;
+        let seen_g = -0.010_248_533 * l + 0.054_019_33 * m - 0.113_614_71 * s;
+        let seen_b = -0.000_365_296_9 * l - 0.004_121_615 * m + 0.693_511_4 * s;
+        let (error_r, error_g, error_b) = (color.r - seen_r, color.g - seen_g, color.b - seen_b);
+        RGB::from_f32(
+            color.r.clamp(0.0, 1.0),
+            (color.g + 0.7 * error_r + error_g).clamp(0.0, 1.0),
+            (color.b + 0.7 * error_r + error_b).clamp(0.0, 1.0),
+        )
+    }
+}
+
+/// Letters at the full brightness of their hue, greys become white
+fn high_contrast_foreground(color: RGB) -> RGB {
+    let brightest = color.r.max(color.g).max(color.b);
+    if brightest <= 0.0 { return color; }
+    RGB::from_f32(color.r / brightest, color.g / brightest, color.b / brightest)
+}
+
+fn adjust_tile_color(settings: &Settings, color: RGBA, foreground: bool) -> RGBA {
+    let mut rgb = settings.color_scheme.adjust(color.to_rgb());
+    if settings.high_contrast {
+        rgb = match foreground {
+            true => high_contrast_foreground(rgb),
+            false => rgb * HIGH_CONTRAST_BACKGROUND_SCALE,
+        };
+    }
+    rgb.to_rgba(color.a)
+}
+
+/// Recolours everything drawn in this frame for the colour scheme and the contrast setting, so
+/// each screen keeps its own colours and still follows them. Called once at the end of a tick.
+pub fn apply_settings(ctx: &Rltk, settings: &Settings) {
+    if settings.color_scheme == ColorScheme::Standard && !settings.high_contrast { return; }
+    let mut backend = BACKEND_INTERNAL.lock();
+    let Some(console) = backend.consoles[ctx.active_console].console.as_any_mut().downcast_mut::<SimpleConsole>() else { return; };
+    for tile in console.tiles.iter_mut() {
+        tile.fg = adjust_tile_color(settings, tile.fg, true);
+        tile.bg = adjust_tile_color(settings, tile.bg, false);
+    }
+    console.is_dirty = true;
+}
@@ -0,0 +1,135 @@
+use std::fmt::Formatter;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{Error, Visitor};
+use crate::constants::{CLASS_HEIGHT, CLASS_WIDTH, CLASS_X, CLASS_Y, CURRENT_DATE, HOME_HEIGHT, HOME_WIDTH, HOME_X, HOME_Y, LIBRARY_HEIGHT, LIBRARY_WIDTH, LIBRARY_X, LIBRARY_Y, OTTOMAN_BOTTOM_HEIGHT, OTTOMAN_BOTTOM_WIDTH, OTTOMAN_BOTTOM_X, OTTOMAN_BOTTOM_Y, OTTOMAN_LEFT_HEIGHT, OTTOMAN_LEFT_WIDTH, OTTOMAN_LEFT_X, OTTOMAN_LEFT_Y, OTTOMAN_MAIN_HEIGHT, OTTOMAN_MAIN_WIDTH, OTTOMAN_MAIN_X, OTTOMAN_MAIN_Y, OTTOMAN_RIGHT_HEIGHT, OTTOMAN_RIGHT_WIDTH, OTTOMAN_RIGHT_X, OTTOMAN_RIGHT_Y, OTTOMAN_TOP_HEIGHT, OTTOMAN_TOP_WIDTH, OTTOMAN_TOP_X, OTTOMAN_TOP_Y, PAST_DATE, PLACE_CLASS_NAME, PLACE_HOME_NAME, PLACE_LIB_NAME, PLACE_OTTOMAN_BOTTOM_NAME, PLACE_OTTOMAN_LEFT_NAME, PLACE_OTTOMAN_MAIN_NAME, PLACE_OTTOMAN_RIGHT_NAME, PLACE_OTTOMAN_TOP_NAME, PLACE_SCHOOL_NORTH_NAME, PLACE_SCHOOL_SOUTH_NAME, SCHOOL_NORTH_HEIGHT, SCHOOL_NORTH_WIDTH, SCHOOL_NORTH_X, SCHOOL_NORTH_Y, SCHOOL_SOUTH_HEIGHT, SCHOOL_SOUTH_WIDTH, SCHOOL_SOUTH_X, SCHOOL_SOUTH_Y};
+use crate::i18n::tr;
+use crate::rect::Rect;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Era {
+    Present,
+    Past,
+}
+
+impl Era {
+    pub fn get_year(&self) -> &'static str {
+        match self {
+            Era::Present => CURRENT_DATE,
+            Era::Past => PAST_DATE,
+        }
+    }
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum LayoutSource {
+    /// A single room covering the bounds
+    Fixed,
+    /// Rooms and corridors generated from the run's seed, the bounds are used when generation is off
+    Generated,
+}
+
+pub struct PlaceDefinition {
+    /// Stable identifier, used in save files
+    pub id: &'static str,
+    /// Key into the string tables
+    pub name: &'static str,
+    pub era: Era,
+    pub layout: LayoutSource,
+    pub bounds: (i32, i32, i32, i32),
+}
+
+/// Every place of the game. New places are added here and get a `Place` constant below; keep
+/// appending at the end, since the index of a place also seeds its generated layout.
+pub const PLACES: &[PlaceDefinition] = &[
+    PlaceDefinition { id: "Home", name: PLACE_HOME_NAME, era: Era::Present, layout: LayoutSource::Fixed, bounds: (HOME_X, HOME_Y, HOME_WIDTH, HOME_HEIGHT) },
+    PlaceDefinition { id: "SchoolSouth", name: PLACE_SCHOOL_SOUTH_NAME, era: Era::Present, layout: LayoutSource::Fixed, bounds: (SCHOOL_SOUTH_X, SCHOOL_SOUTH_Y, SCHOOL_SOUTH_WIDTH, SCHOOL_SOUTH_HEIGHT) },
+    PlaceDefinition { id: "SchoolNorth", name: PLACE_SCHOOL_NORTH_NAME, era: Era::Present, layout: LayoutSource::Fixed, bounds: (SCHOOL_NORTH_X, SCHOOL_NORTH_Y, SCHOOL_NORTH_WIDTH, SCHOOL_NORTH_HEIGHT) },
+    PlaceDefinition { id: "Class", name: PLACE_CLASS_NAME, era: Era::Present, layout: LayoutSource::Fixed, bounds: (CLASS_X, CLASS_Y, CLASS_WIDTH, CLASS_HEIGHT) },
+    PlaceDefinition { id: "Library", name: PLACE_LIB_NAME, era: Era::Present, layout: LayoutSource::Fixed, bounds: (LIBRARY_X, LIBRARY_Y, LIBRARY_WIDTH, LIBRARY_HEIGHT) },
+    PlaceDefinition { id: "OttomanMain", name: PLACE_OTTOMAN_MAIN_NAME, era: Era::Past, layout: LayoutSource::Fixed, bounds: (OTTOMAN_MAIN_X, OTTOMAN_MAIN_Y, OTTOMAN_MAIN_WIDTH, OTTOMAN_MAIN_HEIGHT) },
+    PlaceDefinition { id: "OttomanLeft", name: PLACE_OTTOMAN_LEFT_NAME, era: Era::Past, layout: LayoutSource::Generated, bounds: (OTTOMAN_LEFT_X, OTTOMAN_LEFT_Y, OTTOMAN_LEFT_WIDTH, OTTOMAN_LEFT_HEIGHT) },
+    PlaceDefinition { id: "OttomanRight", name: PLACE_OTTOMAN_RIGHT_NAME, era: Era::Past, layout: LayoutSource::Generated, bounds: (OTTOMAN_RIGHT_X, OTTOMAN_RIGHT_Y, OTTOMAN_RIGHT_WIDTH, OTTOMAN_RIGHT_HEIGHT) },
+    PlaceDefinition { id: "OttomanTop", name: PLACE_OTTOMAN_TOP_NAME, era: Era::Past, layout: LayoutSource::Generated, bounds: (OTTOMAN_TOP_X, OTTOMAN_TOP_Y, OTTOMAN_TOP_WIDTH, OTTOMAN_TOP_HEIGHT) },
+    PlaceDefinition { id: "OttomanBottom", name: PLACE_OTTOMAN_BOTTOM_NAME, era: Era::Past, layout: LayoutSource::Generated, bounds: (OTTOMAN_BOTTOM_X, OTTOMAN_BOTTOM_Y, OTTOMAN_BOTTOM_WIDTH, OTTOMAN_BOTTOM_HEIGHT) },
+];
+
+/// Index into `PLACES`
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Place(usize);
+
+impl Place {
+    pub const HOME: Place = Place(0);
+    pub const SCHOOL_SOUTH: Place = Place(1);
+    pub const SCHOOL_NORTH: Place = Place(2);
+    pub const CLASS: Place = Place(3);
+    pub const LIBRARY: Place = Place(4);
+    pub const OTTOMAN_MAIN: Place = Place(5);
+    pub const OTTOMAN_LEFT: Place = Place(6);
+    pub const OTTOMAN_RIGHT: Place = Place(7);
+    pub const OTTOMAN_TOP: Place = Place(8);
+    pub const OTTOMAN_BOTTOM: Place = Place(9);
+
+    pub fn all() -> impl Iterator<Item=Place> {
+        (0..PLACES.len()).map(Place)
+    }
+
+    pub fn from_id(id: &str) -> Option<Place> {
+        PLACES.iter().position(|def| def.id == id).map(Place)
+    }
+
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    pub fn definition(&self) -> &'static PlaceDefinition {
+        &PLACES[self.0]
+    }
+
+    pub fn get_name(&self) -> String {
+        tr(self.definition().name)
+    }
+
+    pub fn get_year(&self) -> String {
+        String::from(self.definition().era.get_year())
+    }
+
+    pub fn get_era(&self) -> Era {
+        self.definition().era
+    }
+
+    pub fn get_bounds(&self) -> Rect {
+        let (x, y, width, height) = self.definition().bounds;
+        Rect::new(x, y, width, height)
+    }
+
+    /// Side streets that get a generated layout when generation is enabled
+    pub fn is_generated(&self) -> bool {
+        self.definition().layout == LayoutSource::Generated
+    }
+}
+
+impl Serialize for Place {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.definition().id)
+    }
+}
+
+struct PlaceVisitor;
+
+impl<'de> Visitor<'de> for PlaceVisitor {
+    type Value = Place;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("the id of a registered place")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Place::from_id(v).ok_or_else(|| E::custom(format!("unknown place: {}", v)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Place {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(PlaceVisitor)
+    }
+}
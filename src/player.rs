@@ -1,7 +1,32 @@
-use rltk::{VirtualKeyCode, Rltk, Point};
+use rltk::{Rltk, Point};
 use specs::prelude::*;
-use crate::constants::{MAP_HEIGHT, MAP_WIDTH};
-use super::{Position, Player, TileType, Map, State, RunState, TargetedPosition, Place, Portal, BelongsTo};
+use crate::constants::{CONSOLE_COMPANION_BEFRIENDED, MAP_HEIGHT, MAP_WIDTH};
+use crate::events::{GameEvent, GameEvents};
+use crate::clock::GameClock;
+use crate::gamelog::{GameLog, LogCategory};
+use crate::gui::PauseSelection;
+use crate::i18n::tr;
+use crate::items::ItemName;
+use crate::keybindings::{Action, KeyBindings};
+use super::{Position, Player, TileType, Map, State, RunState, TargetedPosition, Place, Portal, BelongsTo, DormantPosition, Befriendable, Companion, Item, Movement, Name, Stored};
+
+/// Set when the player spends a turn, NPCs only move after the player did
+pub struct PlayerTurn {
+    pub acted: bool,
+}
+
+/// Remaining steps of a click-to-travel walk, one is taken per tick
+pub struct AutoWalk {
+    pub path: Vec<usize>,
+    /// Number of revealed dormant entities when the walk started
+    pub revealed: usize,
+}
+
+impl AutoWalk {
+    pub fn is_walking(&self) -> bool {
+        !self.path.is_empty()
+    }
+}
 
 pub fn try_to_move_player(delta_x: i32, delta_y: i32, ecs: &mut World) -> RunState {
     let mut player_point = ecs.write_resource::<Point>();
@@ -14,7 +39,7 @@ pub fn try_to_move_player(delta_x: i32, delta_y: i32, ecs: &mut World) -> RunSta
         TileType::Floor => {
             player_point.x = new_x;
             player_point.y = new_y;
-            println!("x: {}, y: {}", new_x, new_y);
+            ecs.write_resource::<PlayerTurn>().acted = true;
         }
         TileType::RequiresKey => {
             let mut targeted_pos = ecs.write_resource::<TargetedPosition>();
@@ -39,6 +64,7 @@ pub fn try_to_move_player(delta_x: i32, delta_y: i32, ecs: &mut World) -> RunSta
                 for (bel, _) in (&mut belongs, &mut player).join() {
                     bel.domain = domain;
                 }
+                ecs.write_resource::<GameEvents>().push(GameEvent::PlaceEntered(domain));
             }
         }
         TileType::NPC => {
@@ -52,18 +78,158 @@ pub fn try_to_move_player(delta_x: i32, delta_y: i32, ecs: &mut World) -> RunSta
     RunState::Game
 }
 
+/// The NPC standing on the targeted position, if it is in the current place
+pub fn targeted_npc<T: Component>(ecs: &World) -> Option<Entity> {
+    let target = ecs.fetch::<TargetedPosition>();
+    let current_place = ecs.fetch::<Place>();
+    let markers = ecs.read_storage::<T>();
+    let positions = ecs.read_storage::<Position>();
+    let belongs = ecs.read_storage::<BelongsTo>();
+    let entities = ecs.entities();
+    (&markers, &positions, &belongs, &entities).join()
+        .find(|(_, pos, bel, _)| bel.domain == *current_place && pos.x == target.x && pos.y == target.y)
+        .map(|(_, _, _, ent)| ent)
+}
+
+/// Feeding an animal makes it the player's companion, there can only be one at a time
+fn try_to_befriend(ecs: &mut World) {
+    if (&ecs.read_storage::<Companion>()).join().next().is_some() { return; }
+    let Some(animal) = targeted_npc::<Befriendable>(ecs) else { return; };
+    let food = {
+        let items = ecs.read_storage::<Item>();
+        let stored = ecs.read_storage::<Stored>();
+        let entities = ecs.entities();
+        (&items, &stored, &entities).join()
+            .find(|(item, _, _)| item.name == ItemName::PetFood)
+            .map(|(_, _, ent)| ent)
+    };
+    let Some(food) = food else { return; };
+    ecs.delete_entity(food).expect("Unable to delete the pet food");
+    ecs.write_storage::<Movement>().remove(animal);
+    ecs.write_storage::<Companion>().insert(animal, Companion { reaction_cooldown: 0 }).expect("Couldn't insert companion");
+    let name = ecs.read_storage::<Name>().get(animal).unwrap().name.clone();
+    let clock = *ecs.fetch::<GameClock>();
+    ecs.write_resource::<GameLog>().push(LogCategory::Quest, &clock, format!("{} {}", tr(&name), tr(CONSOLE_COMPANION_BEFRIENDED)));
+}
+
+/// Bumping into the companion swaps places with it instead of talking
+fn swap_with_companion(ecs: &mut World) -> bool {
+    let Some(companion) = targeted_npc::<Companion>(ecs) else { return false; };
+    let mut player_point = ecs.write_resource::<Point>();
+    let mut positions = ecs.write_storage::<Position>();
+    let mut map = ecs.write_resource::<Map>();
+    let pos = positions.get_mut(companion).unwrap();
+    map.tiles[Map::xy_to_tile(player_point.x, player_point.y)] = TileType::NPC;
+    map.tiles[Map::xy_to_tile(pos.x, pos.y)] = TileType::Floor;
+    std::mem::swap(&mut pos.x, &mut player_point.x);
+    std::mem::swap(&mut pos.y, &mut player_point.y);
+    ecs.write_resource::<PlayerTurn>().acted = true;
+    true
+}
+
+/// Moves the player, handling what bumping into an animal means before the dialogue starts
+pub fn move_player(delta_x: i32, delta_y: i32, ecs: &mut World) -> RunState {
+    let state = try_to_move_player(delta_x, delta_y, ecs);
+    if let RunState::InteractNpc { .. } = state {
+        if swap_with_companion(ecs) {
+            return RunState::Game;
+        }
+        try_to_befriend(ecs);
+    }
+    state
+}
+
+fn revealed_count(ecs: &World) -> usize {
+    let dormant_positions = ecs.read_storage::<DormantPosition>();
+    let positions = ecs.read_storage::<Position>();
+    (&dormant_positions, &positions).join().count()
+}
+
+/// Plans a path to the tile, NPCs, doors and portals are walked up to and bumped into
+pub fn travel_to(x: i32, y: i32, ecs: &mut World) {
+    let steps = {
+        let map = ecs.fetch::<Map>();
+        if x < 0 || x >= map.width || y < 0 || y >= map.height { return; }
+        let target = Map::xy_to_tile(x, y);
+        let mut pathing_map = (*map).clone();
+        match pathing_map.tiles[target] {
+            TileType::Floor => {}
+            TileType::NPC | TileType::Portal | TileType::RequiresKey => pathing_map.tiles[target] = TileType::Floor,
+            _ => return,
+        }
+        let player_point = ecs.fetch::<Point>();
+        let path = rltk::a_star_search(Map::xy_to_tile(player_point.x, player_point.y), target, &pathing_map);
+        if !path.success { return; }
+        path.steps
+    };
+    let revealed = revealed_count(ecs);
+    let mut walk = ecs.write_resource::<AutoWalk>();
+    walk.path = steps.into_iter().skip(1).collect();
+    walk.revealed = revealed;
+}
+
+/// The step to a tile next to the player, clicking one acts like the key of that direction so
+/// doors and NPCs respond in the same tick
+fn adjacent_delta(x: i32, y: i32, ecs: &World) -> Option<(i32, i32)> {
+    let map = ecs.fetch::<Map>();
+    if x < 0 || x >= map.width || y < 0 || y >= map.height { return None; }
+    let player_point = ecs.fetch::<Point>();
+    let (delta_x, delta_y) = (x - player_point.x, y - player_point.y);
+    match (delta_x, delta_y) {
+        (0, 0) => None,
+        (-1..=1, -1..=1) => Some((delta_x, delta_y)),
+        _ => None,
+    }
+}
+
+fn stop_auto_walk(ecs: &mut World) {
+    ecs.write_resource::<AutoWalk>().path.clear();
+}
+
+/// Takes the next step of the walk, stopping when something gets in the way or is revealed
+fn continue_auto_walk(ecs: &mut World) -> RunState {
+    if revealed_count(ecs) != ecs.fetch::<AutoWalk>().revealed {
+        stop_auto_walk(ecs);
+        return RunState::Game;
+    }
+    let next = ecs.write_resource::<AutoWalk>().path.remove(0);
+    let (next_x, next_y) = (next as i32 % MAP_WIDTH, next as i32 / MAP_WIDTH);
+    let player_point = *ecs.fetch::<Point>();
+    let state = move_player(next_x - player_point.x, next_y - player_point.y, ecs);
+    let moved = {
+        let player_point = ecs.fetch::<Point>();
+        player_point.x == next_x && player_point.y == next_y
+    };
+    if state != RunState::Game || !moved {
+        stop_auto_walk(ecs);
+    }
+    state
+}
 
 pub fn player_input(gs: &mut State, ctx: &mut Rltk) -> RunState {
-    match ctx.key {
-        None => {}
-        Some(key) => match key {
-            VirtualKeyCode::Up => return try_to_move_player(0, -1, &mut gs.ecs),
-            VirtualKeyCode::Down => return try_to_move_player(0, 1, &mut gs.ecs),
-            VirtualKeyCode::Left => return try_to_move_player(-1, 0, &mut gs.ecs),
-            VirtualKeyCode::Right => return try_to_move_player(1, 0, &mut gs.ecs),
-            VirtualKeyCode::Escape => return RunState::SaveGame,
-            _ => {}
+    if gs.ecs.fetch::<AutoWalk>().is_walking() {
+        if ctx.key.is_some() || ctx.left_click {
+            stop_auto_walk(&mut gs.ecs);
+            return RunState::Game;
+        }
+        return continue_auto_walk(&mut gs.ecs);
+    }
+    if ctx.left_click {
+        let (mouse_x, mouse_y) = ctx.mouse_pos();
+        if let Some((delta_x, delta_y)) = adjacent_delta(mouse_x, mouse_y, &gs.ecs) {
+            return move_player(delta_x, delta_y, &mut gs.ecs);
+        }
+        travel_to(mouse_x, mouse_y, &mut gs.ecs);
+        return RunState::Game;
+    }
+    let action = gs.ecs.fetch::<KeyBindings>().action(ctx.key);
+    match action {
+        Some(Action::Cancel) => RunState::Paused { selected: PauseSelection::Resume, confirm: None },
+        Some(Action::History) => RunState::LogHistory { scroll: 0, filter: None },
+        Some(action) => match action.direction() {
+            Some((delta_x, delta_y)) => move_player(delta_x, delta_y, &mut gs.ecs),
+            None => RunState::Game
         },
+        None => RunState::Game
     }
-    RunState::Game
 }
@@ -0,0 +1,140 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+use rltk::Rltk;
+use serde::{Deserialize, Serialize};
+use specs::{World, WorldExt};
+use crate::clock::GameClock;
+use crate::constants::{PROFILES_PATH, SETTINGS_PATH};
+use crate::gamelog::GameLog;
+use crate::i18n::set_language;
+use crate::layout::ScreenSize;
+use crate::map::MapGeneration;
+use crate::palette::EraTransition;
+use crate::settings::Settings;
+
+/// A finished game
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Playthrough {
+    /// Seconds since the Unix epoch when the game was won
+    pub finished_at: u64,
+    pub seed: u64,
+    pub turns: u64,
+    /// In-game minutes since midnight of the first day
+    pub minutes: u64,
+}
+
+/// Progress of one student, kept across sessions and playthroughs
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Profile {
+    pub name: String,
+    pub playthroughs: Vec<Playthrough>,
+    /// Name keys of the NPCs whose lectures the player has heard to the end
+    pub codex: BTreeSet<String>,
+    /// First-time hints that were already shown
+    pub hints_seen: HashSet<String>,
+    /// Ids of the unlocked achievements, see `Achievement::id`
+    pub achievements: BTreeSet<String>,
+    pub settings: Settings,
+}
+
+/// Every profile on disk, with the one chosen at startup
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Profiles {
+    pub profiles: Vec<Profile>,
+    #[serde(skip)]
+    pub active: Option<usize>,
+}
+
+impl Profiles {
+    /// Reads the profiles file, a missing or broken one gives no profiles
+    pub fn load() -> Self {
+        fs::read_to_string(PROFILES_PATH).ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(PROFILES_PATH, contents)
+    }
+
+    pub fn active(&self) -> Option<&Profile> {
+        self.active.map(|index| &self.profiles[index])
+    }
+
+    pub fn active_mut(&mut self) -> Option<&mut Profile> {
+        self.active.map(|index| &mut self.profiles[index])
+    }
+
+    /// Makes the profile of the name the active one, a new name starts a profile with the given
+    /// settings
+    fn select(&mut self, name: &str, settings: &Settings) -> &Profile {
+        let index = match self.profiles.iter().position(|profile| profile.name == name) {
+            Some(index) => index,
+            None => {
+                self.profiles.push(Profile {
+                    name: name.to_string(),
+                    settings: settings.clone(),
+                    ..Profile::default()
+                });
+                self.profiles.len() - 1
+            }
+        };
+        self.active = Some(index);
+        &self.profiles[index]
+    }
+}
+
+/// Switches to the profile and to the settings it was last played with
+pub fn select_profile(ecs: &mut World, ctx: &mut Rltk, name: &str) {
+    let settings = {
+        let mut profiles = ecs.write_resource::<Profiles>();
+        let current = ecs.fetch::<Settings>().clone();
+        let settings = profiles.select(name, &current).settings.clone();
+        if profiles.save().is_err() {
+            ecs.write_resource::<GameLog>().push_write_failure(&ecs.fetch::<GameClock>(), PROFILES_PATH);
+        }
+        settings
+    };
+    if let Some(language) = settings.language() {
+        set_language(language);
+    }
+    ecs.write_resource::<EraTransition>().enabled = settings.era_transition;
+    ecs.write_resource::<ScreenSize>().set_tile_pixels(ctx, settings.tile_pixels);
+    if settings.save().is_err() {
+        ecs.write_resource::<GameLog>().push_write_failure(&ecs.fetch::<GameClock>(), SETTINGS_PATH);
+    }
+    ecs.insert(settings);
+}
+
+/// Saves the settings as the defaults of the machine and as those of the active profile
+pub fn save_settings(ecs: &World) {
+    let settings = ecs.fetch::<Settings>();
+    if settings.save().is_err() {
+        ecs.write_resource::<GameLog>().push_write_failure(&ecs.fetch::<GameClock>(), SETTINGS_PATH);
+    }
+    let mut profiles = ecs.write_resource::<Profiles>();
+    if let Some(profile) = profiles.active_mut() {
+        profile.settings = (*settings).clone();
+        if profiles.save().is_err() {
+            ecs.write_resource::<GameLog>().push_write_failure(&ecs.fetch::<GameClock>(), PROFILES_PATH);
+        }
+    }
+}
+
+/// Adds the won game to the playthroughs of the active profile
+pub fn record_playthrough(ecs: &World) {
+    let clock = *ecs.fetch::<GameClock>();
+    let seed = ecs.fetch::<MapGeneration>().seed;
+    let finished_at = SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or(0);
+    let mut profiles = ecs.write_resource::<Profiles>();
+    if let Some(profile) = profiles.active_mut() {
+        profile.playthroughs.push(Playthrough { finished_at, seed, turns: clock.turns, minutes: clock.minutes });
+        if profiles.save().is_err() {
+            ecs.write_resource::<GameLog>().push_write_failure(&clock, PROFILES_PATH);
+        }
+    }
+}
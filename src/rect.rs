@@ -1,3 +1,4 @@
+#[derive(Clone, Copy)]
 pub struct Rect {
     pub x1: i32,
     pub y1: i32,
@@ -10,4 +11,17 @@ impl Rect {
     pub fn new(x1: i32, y1: i32, w: i32, h: i32) -> Self {
         Self { x1, y1, x2: x1 + w, y2: y1 + h, center: (x1 + w / 2, y1 + h / 2) }
     }
-}
\ No newline at end of file
+
+    pub fn width(&self) -> i32 {
+        self.x2 - self.x1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.y2 - self.y1
+    }
+
+    /// Returns true if the rooms overlap or would share a wall
+    pub fn intersect(&self, other: &Rect) -> bool {
+        self.x1 <= other.x2 + 1 && self.x2 + 1 >= other.x1 && self.y1 <= other.y2 + 1 && self.y2 + 1 >= other.y1
+    }
+}
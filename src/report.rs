@@ -0,0 +1,167 @@
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use specs::{Join, World, WorldExt};
+use crate::{Befriendable, Interaction, Name, Npc, Place, PlayerName, PLACES};
+use crate::clock::GameClock;
+use crate::constants::{REPORT_GRADE_A_STR, REPORT_GRADE_B_STR, REPORT_GRADE_C_STR, REPORT_GRADE_D_STR, REPORT_GRADE_F_STR, REPORT_GRADE_STR, REPORT_LECTURES_STR, REPORT_FILE, REPORT_NPCS_STR, REPORT_PLACES_STR, REPORT_PLAY_TIME_STR, REPORT_TITLE_STR, REPORT_TURNS_STR};
+use crate::i18n::tr;
+use crate::items::ItemName;
+use crate::settings::Settings;
+
+/// What the player did in the current game, saved with it
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameStatistics {
+    /// Seconds spent in the game itself, menus and pauses left out
+    pub played_seconds: f64,
+    /// In the order they were first entered
+    pub places_visited: Vec<Place>,
+    /// Name keys of the NPCs the player talked to
+    pub npcs_spoken_to: Vec<String>,
+    /// Name keys of the NPCs whose lectures the player heard to the end
+    pub lectures_completed: Vec<String>,
+    /// Picked up, handed over or crafted, in the order they were earned
+    pub items_earned: Vec<ItemName>,
+}
+
+impl GameStatistics {
+    pub fn new(start: Place) -> Self {
+        GameStatistics { places_visited: vec![start], ..GameStatistics::default() }
+    }
+
+    pub fn visit(&mut self, place: Place) {
+        if !self.places_visited.contains(&place) {
+            self.places_visited.push(place);
+        }
+    }
+
+    pub fn speak_to(&mut self, name: &str) {
+        if !self.npcs_spoken_to.iter().any(|spoken| spoken == name) {
+            self.npcs_spoken_to.push(name.to_string());
+        }
+    }
+
+    pub fn earn(&mut self, item: ItemName) {
+        if !self.items_earned.contains(&item) {
+            self.items_earned.push(item);
+        }
+    }
+
+    pub fn complete_lecture(&mut self, name: &str) {
+        if !self.lectures_completed.iter().any(|completed| completed == name) {
+            self.lectures_completed.push(name.to_string());
+        }
+    }
+}
+
+/// A lecture is anything longer than the single line the passers-by and the animals say
+pub fn is_lecture(interaction: &Interaction) -> bool {
+    interaction.dialogues.iter().map(|page| page.len()).sum::<usize>() > 1
+}
+
+/// Name keys of the NPCs who give a lecture, the animals and passers-by left out
+pub fn lecturers(ecs: &World) -> Vec<String> {
+    let names = ecs.read_storage::<Name>();
+    let interactions = ecs.read_storage::<Interaction>();
+    let animals = ecs.read_storage::<Befriendable>();
+    (&names, &interactions, !&animals).join()
+        .filter(|(_, interaction, _)| is_lecture(interaction))
+        .map(|(name, _, _)| name.name.clone())
+        .collect()
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Grade { A, B, C, D, F }
+
+impl Grade {
+    /// Lectures weigh as much as walking around and talking to people together
+    fn from_shares(lectures: f64, places: f64, npcs: f64) -> Grade {
+        match (2.0 * lectures + places + npcs) / 4.0 {
+            score if score >= 0.9 => Grade::A,
+            score if score >= 0.75 => Grade::B,
+            score if score >= 0.6 => Grade::C,
+            score if score >= 0.45 => Grade::D,
+            _ => Grade::F,
+        }
+    }
+
+    pub fn get_comment(&self) -> String {
+        tr(match self {
+            Grade::A => REPORT_GRADE_A_STR,
+            Grade::B => REPORT_GRADE_B_STR,
+            Grade::C => REPORT_GRADE_C_STR,
+            Grade::D => REPORT_GRADE_D_STR,
+            Grade::F => REPORT_GRADE_F_STR,
+        })
+    }
+}
+
+/// The report card of a finished game, made from its statistics
+pub struct Report {
+    pub player: String,
+    pub played_seconds: u64,
+    pub turns: u64,
+    /// Done and possible counts
+    pub places: (usize, usize),
+    pub npcs: (usize, usize),
+    pub lectures: (usize, usize),
+    pub grade: Grade,
+}
+
+fn share((done, possible): (usize, usize)) -> f64 {
+    if possible == 0 { 1.0 } else { done as f64 / possible as f64 }
+}
+
+impl Report {
+    pub fn new(ecs: &World) -> Report {
+        let statistics = ecs.fetch::<GameStatistics>();
+        let names = ecs.read_storage::<Name>();
+        let npcs = ecs.read_storage::<Npc>();
+        let animals = ecs.read_storage::<Befriendable>();
+        let interactions = ecs.read_storage::<Interaction>();
+        let npc_count = (&names, &npcs).join().count();
+        let lecture_count = (&names, &npcs, &interactions, !&animals).join().filter(|(_, _, interaction, _)| is_lecture(interaction)).count();
+
+        let places = (statistics.places_visited.len(), PLACES.len());
+        let npcs = (statistics.npcs_spoken_to.len(), npc_count);
+        let lectures = (statistics.lectures_completed.len(), lecture_count);
+        Report {
+            player: ecs.fetch::<PlayerName>().name.clone(),
+            played_seconds: statistics.played_seconds as u64,
+            turns: ecs.fetch::<GameClock>().turns,
+            places,
+            npcs,
+            lectures,
+            grade: Grade::from_shares(share(lectures), share(places), share(npcs)),
+        }
+    }
+
+    pub fn play_time(&self) -> String {
+        format!("{:02}:{:02}:{:02}", self.played_seconds / 3600, self.played_seconds / 60 % 60, self.played_seconds % 60)
+    }
+
+    /// Label and value of every line, the grade comes last
+    pub fn lines(&self) -> Vec<(String, String)> {
+        vec![
+            (tr(REPORT_PLAY_TIME_STR), self.play_time()),
+            (tr(REPORT_TURNS_STR), self.turns.to_string()),
+            (tr(REPORT_PLACES_STR), format!("{}/{}", self.places.0, self.places.1)),
+            (tr(REPORT_NPCS_STR), format!("{}/{}", self.npcs.0, self.npcs.1)),
+            (tr(REPORT_LECTURES_STR), format!("{}/{}", self.lectures.0, self.lectures.1)),
+            (tr(REPORT_GRADE_STR), format!("{:?}", self.grade)),
+        ]
+    }
+
+    /// Writes the report as Markdown to the export directory, returns where it went
+    pub fn export(&self, settings: &Settings) -> std::io::Result<PathBuf> {
+        let path = settings.prepare_export(REPORT_FILE)?;
+        let mut contents = format!("# {}: {}\n\n", tr(REPORT_TITLE_STR), self.player);
+        for (label, value) in self.lines() {
+            contents.push_str(&format!("- {} {}\n", label, value));
+        }
+        contents.push_str(&format!("\n> {}\n", self.grade.get_comment()));
+        fs::write(&path, contents)?;
+        Ok(path)
+    }
+}
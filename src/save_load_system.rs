@@ -7,6 +7,22 @@ use specs::error::NoError;
 use super::components::*;
 
 const SAVE_PATH: &str = "./save_game.json";
+
+/// Turn count of the last save or load, the game has unsaved progress when the clock moved on
+pub struct LastSave {
+    pub turns: Option<u64>,
+}
+
+impl LastSave {
+    pub fn has_unsaved_progress(&self, ecs: &World) -> bool {
+        self.turns != Some(ecs.fetch::<super::clock::GameClock>().turns)
+    }
+}
+
+fn mark_saved(ecs: &mut World) {
+    let turns = ecs.fetch::<super::clock::GameClock>().turns;
+    ecs.write_resource::<LastSave>().turns = Some(turns);
+}
 macro_rules! serialize_individually {
     ($ecs:expr, $ser:expr, $data:expr, $( $type:ty),*) => {
         $(
@@ -37,9 +53,13 @@ macro_rules! deserialize_individually {
 }
 pub fn save_game(ecs: &mut World) {
     let map_copy = ecs.get_mut::<super::map::Map>().unwrap().clone();
+    let generation = *ecs.fetch::<super::map::MapGeneration>();
+    let flags = (*ecs.fetch::<super::triggers::Flags>()).clone();
+    let clock = *ecs.fetch::<super::clock::GameClock>();
+    let statistics = (*ecs.fetch::<super::report::GameStatistics>()).clone();
     let save_helper = ecs
         .create_entity()
-        .with(SerializationHelper { map: map_copy })
+        .with(SerializationHelper { map: map_copy, generation, flags, clock, statistics })
         .marked::<SimpleMarker<SerializeMe>>()
         .build();
     {
@@ -50,12 +70,13 @@ pub fn save_game(ecs: &mut World) {
         serialize_individually!(ecs, serializer, data, Position, TargetedPosition, Renderable,
             Player, Name, Item, Stored, Impassable, RequiresItem, ContainsItem, RequiresItems, ContainsItems,
             PermanentItem, SerializationHelper, Portal, BelongsTo, Npc, Objective, Interaction, DormantPosition,
-            RevealerInformation, PlayerName
+            Trigger, PlayerName, Movement, Befriendable, Companion
         );
     }
 
     // Clean up
     ecs.delete_entity(save_helper).expect("Crash on cleanup");
+    mark_saved(ecs);
 }
 
 pub fn save_exists() -> bool {
@@ -83,7 +104,7 @@ pub fn load_game(ecs: &mut World) {
         deserialize_individually!(ecs, de, d, Position, TargetedPosition, Renderable,
             Player, Name, Item, Stored, Impassable, RequiresItem, ContainsItem, RequiresItems, ContainsItems,
             PermanentItem, SerializationHelper, Portal, BelongsTo, Npc, Objective, Interaction, DormantPosition,
-            RevealerInformation, PlayerName
+            Trigger, PlayerName, Movement, Befriendable, Companion
         );
     }
 
@@ -93,17 +114,31 @@ pub fn load_game(ecs: &mut World) {
         let helper = ecs.read_storage::<SerializationHelper>();
         let player = ecs.read_storage::<Player>();
         let position = ecs.read_storage::<Position>();
+        let belongs = ecs.read_storage::<BelongsTo>();
         for (e, h) in (&entities, &helper).join() {
             let mut world_map = ecs.write_resource::<super::map::Map>();
             *world_map = h.map.clone();
+            let mut generation = ecs.write_resource::<super::map::MapGeneration>();
+            *generation = h.generation;
+            let mut flags = ecs.write_resource::<super::triggers::Flags>();
+            *flags = h.flags.clone();
+            let mut clock = ecs.write_resource::<super::clock::GameClock>();
+            *clock = h.clock;
+            let mut statistics = ecs.write_resource::<super::report::GameStatistics>();
+            *statistics = h.statistics.clone();
             delete_me = Some(e);
         }
-        for (e, _p, pos) in (&entities, &player, &position).join() {
+        for (e, _p, pos, bel) in (&entities, &player, &position, &belongs).join() {
             let mut player_pos = ecs.write_resource::<rltk::Point>();
             *player_pos = rltk::Point::new(pos.x, pos.y);
             let mut player_resource = ecs.write_resource::<Entity>();
             *player_resource = e;
+            // The map of the saved place is built on the next tick
+            let mut current_place = ecs.write_resource::<super::Place>();
+            *current_place = bel.domain;
         }
+        ecs.write_resource::<super::player::AutoWalk>().path.clear();
     }
     ecs.delete_entity(delete_me.unwrap()).expect("Unable to delete helper");
+    mark_saved(ecs);
 }
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::constants::{EXPORT_DIRECTORY, LEARNING_RECORDS_PATH, SETTINGS_PATH, TILE_PIXELS};
+use crate::i18n::{set_language, Language};
+use crate::palette::ColorScheme;
+
+/// Choices of the settings screen, kept between sessions in the settings file
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct Settings {
+    pub era_transition: bool,
+    /// Id of the language, see `Language::id`
+    pub language: String,
+    pub color_scheme: ColorScheme,
+    pub high_contrast: bool,
+    /// Marks the selected menu entry and locked doors with shapes, not only with colours
+    pub markers: bool,
+    /// Size of a letter on the screen, only the window build can change it
+    pub tile_pixels: i32,
+    /// Shows the first-time hints
+    pub hints: bool,
+    /// Where the report card and the study sheet are written
+    pub export_directory: String,
+    /// Keeps learning records of what the player experiences and completes, off unless the
+    /// player or the school turns it on
+    pub learning_records: bool,
+    /// File the records are appended to, one JSON statement per line
+    pub learning_records_file: String,
+    /// `http://host:port/path` the records are also posted to, empty for none
+    pub learning_records_endpoint: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            era_transition: true,
+            language: Language::Turkish.id().to_string(),
+            color_scheme: ColorScheme::Standard,
+            high_contrast: false,
+            markers: false,
+            tile_pixels: TILE_PIXELS,
+            hints: true,
+            export_directory: EXPORT_DIRECTORY.to_string(),
+            learning_records: false,
+            learning_records_file: LEARNING_RECORDS_PATH.to_string(),
+            learning_records_endpoint: String::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// Reads the settings file, a missing or broken one gives the defaults
+    pub fn load() -> Self {
+        fs::read_to_string(SETTINGS_PATH).ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(SETTINGS_PATH, contents)
+    }
+
+    pub fn language(&self) -> Option<Language> {
+        Language::from_id(&self.language)
+    }
+
+    pub fn export_path(&self, file_name: &str) -> PathBuf {
+        PathBuf::from(&self.export_directory).join(file_name)
+    }
+
+    /// Makes the export directory if it is missing and gives the path of the file in it
+    pub fn prepare_export(&self, file_name: &str) -> std::io::Result<PathBuf> {
+        fs::create_dir_all(&self.export_directory)?;
+        Ok(self.export_path(file_name))
+    }
+
+    /// Switches the interface to the language, it is remembered once the settings are saved
+    pub fn set_language(&mut self, language: Language) {
+        set_language(language);
+        self.language = language.id().to_string();
+    }
+}
@@ -1,16 +1,17 @@
 use rltk::{FontCharType, RGB};
 use specs::prelude::*;
 use specs::saveload::{MarkedBuilder, SimpleMarker};
-use crate::{Name, Player, Position, Renderable, State, Item, RequiresItem, PermanentItem, SerializeMe, Place, BelongsTo, Portal, ContainsItem, Npc, Interaction, RequiresItems, ContainsItems, DormantPosition, RevealerInformation, TileType};
+use crate::{Map, MapGeneration, Name, Player, Position, Renderable, State, Item, RequiresItem, PermanentItem, SerializeMe, Place, BelongsTo, Portal, ContainsItem, Npc, Interaction, RequiresItems, ContainsItems, DormantPosition, Trigger, TileType, Movement, Befriendable};
 use crate::constants::{BACKGROUND_COLOR, ITEM_DOOR_COLOR, ITEM_KEY_COLOR, ITEM_PORTAL_COLOR, KEY_CHAR, NPC_CAT_CHAR, NPC_DOG_CHAR, NPC_HUMAN_CHAR, NPC_HUMAN_COLOR, PLAYER_CHAR, PLAYER_COLOR, PORTAL_CHAR};
 use crate::items::ItemName;
-use crate::npcs::NpcState;
+use crate::npcs::{MovementBehaviour, NpcState};
+use crate::triggers::{TriggerArea, TriggerCondition, TriggerEffect};
 
 pub fn build_player(gs: &mut State, name: String, coord: (i32, i32)) -> Entity {
     gs.ecs
         .create_entity()
         .with(Name { name })
-        .with(BelongsTo { domain: Place::Home })
+        .with(BelongsTo { domain: Place::HOME })
         .with(Position { x: coord.0, y: coord.1 })
         .with(Renderable { glyph: rltk::to_cp437(PLAYER_CHAR), fg: PLAYER_COLOR, bg: BACKGROUND_COLOR, render_order: 0 })
         .with(Player {})
@@ -21,7 +22,7 @@ pub fn build_player(gs: &mut State, name: String, coord: (i32, i32)) -> Entity {
 pub fn build_active_item(gs: &mut State, name: ItemName, domain: Place, coord: (i32, i32), permanent: bool) -> Entity {
     let mut builder = gs.ecs
         .create_entity()
-        .with(Name { name: name.to_string() })
+        .with(Name { name: String::from(name.key()) })
         .with(BelongsTo { domain })
         .with(Position { x: coord.0, y: coord.1 })
         .with(Renderable { glyph: rltk::to_cp437(KEY_CHAR), fg: ITEM_KEY_COLOR, bg: BACKGROUND_COLOR, render_order: 1 })
@@ -82,7 +83,24 @@ pub fn build_dormant_door(gs: &mut State, name: String, domain: Place, coord: (i
             render_order: 1,
         })
         .with(RequiresItem { key })
-        .with(RevealerInformation { x_end_points, y_end_points, revealer_item, before_reveal })
+        .with(Trigger {
+            area: TriggerArea::Area { x_end_points, y_end_points },
+            conditions: vec!(TriggerCondition::HasItem(revealer_item)),
+            effects: vec!(TriggerEffect::RevealDormant),
+            exit_effects: vec!(TriggerEffect::HideDormant { before_reveal }),
+            repeatable: true,
+            active: false,
+            fired: false,
+        })
+        .marked::<SimpleMarker<SerializeMe>>()
+        .build()
+}
+
+pub fn build_trigger(gs: &mut State, domain: Place, area: TriggerArea, conditions: Vec<TriggerCondition>, effects: Vec<TriggerEffect>, exit_effects: Vec<TriggerEffect>, repeatable: bool) -> Entity {
+    gs.ecs
+        .create_entity()
+        .with(BelongsTo { domain })
+        .with(Trigger { area, conditions, effects, exit_effects, repeatable, active: false, fired: false })
         .marked::<SimpleMarker<SerializeMe>>()
         .build()
 }
@@ -130,19 +148,72 @@ pub fn build_npc_human_one_liner(gs: &mut State, name: &str, domain: Place, coor
     build_npc_human(gs, name, domain, coord, vec!(vec!(line)), None, None, vec!(), vec!(), vec!())
 }
 
+pub fn build_npc_human_patrolling(gs: &mut State, name: &str, domain: Place, route: Vec<(i32, i32)>, line: &str) -> Entity {
+    let npc = build_npc(gs, name, domain, route[0], rltk::to_cp437(NPC_HUMAN_CHAR), NPC_HUMAN_COLOR, vec!(vec!(line)), None, None, vec!(), vec!(), vec!());
+    gs.ecs.write_storage::<Movement>().insert(npc, Movement { behaviour: MovementBehaviour::Patrol { route, index: 0 } }).expect("Couldn't insert movement");
+    npc
+}
+
 pub fn build_npc_dog(gs: &mut State, name: &str, domain: Place, coord: (i32, i32), fg: RGB, line: &str) -> Entity {
-    build_npc(gs, name, domain, coord, rltk::to_cp437(NPC_DOG_CHAR), fg, vec!(vec!(line)), None, None, vec!(), vec!(), vec!())
+    let dog = build_npc(gs, name, domain, coord, rltk::to_cp437(NPC_DOG_CHAR), fg, vec!(vec!(line)), None, None, vec!(), vec!(), vec!());
+    gs.ecs.write_storage::<Movement>().insert(dog, Movement { behaviour: MovementBehaviour::Wander }).expect("Couldn't insert movement");
+    gs.ecs.write_storage::<Befriendable>().insert(dog, Befriendable {}).expect("Couldn't insert befriendable");
+    dog
 }
 
 pub fn build_npc_cat(gs: &mut State, name: &str, domain: Place, coord: (i32, i32), fg: RGB, line: &str) -> Entity {
-    build_npc(gs, name, domain, coord, rltk::to_cp437(NPC_CAT_CHAR), fg, vec!(vec!(line)), None, None, vec!(), vec!(), vec!())
+    let cat = build_npc(gs, name, domain, coord, rltk::to_cp437(NPC_CAT_CHAR), fg, vec!(vec!(line)), None, None, vec!(), vec!(), vec!());
+    gs.ecs.write_storage::<Movement>().insert(cat, Movement { behaviour: MovementBehaviour::Wander }).expect("Couldn't insert movement");
+    gs.ecs.write_storage::<Befriendable>().insert(cat, Befriendable {}).expect("Couldn't insert befriendable");
+    cat
 }
 
 pub fn build_dormant_item(gs: &mut State, name: ItemName) -> Entity {
     gs.ecs
         .create_entity()
-        .with(Name { name: name.to_string() })
+        .with(Name { name: String::from(name.key()) })
         .with(Item { name })
         .marked::<SimpleMarker<SerializeMe>>()
         .build()
-}
\ No newline at end of file
+}
+
+/// Moves the portals and NPCs of every generated place onto its generated rooms: each portal
+/// is set into the wall of a room and the portals leading into the place are warped next to it,
+/// while NPCs stand away from the room walls so they never block a way through
+pub fn arrange_generated_places(gs: &mut State) {
+    let generation = *gs.ecs.fetch::<MapGeneration>();
+    if !generation.enabled { return; }
+    let mut positions = gs.ecs.write_storage::<Position>();
+    let mut portals = gs.ecs.write_storage::<Portal>();
+    let belongs = gs.ecs.read_storage::<BelongsTo>();
+    let npcs = gs.ecs.read_storage::<Npc>();
+    for place in Place::all().filter(|place| place.is_generated()) {
+        let (map, rooms, mut rng) = Map::build_layout(place, &generation);
+        let portal_count = (&positions, &portals, &belongs).join().filter(|(_, _, bel)| bel.domain == place).count();
+        let mut tiles = Map::portal_tiles(&rooms, portal_count).into_iter();
+        let mut arrivals: Vec<(Place, (i32, i32))> = Vec::new();
+        for (pos, portal, bel) in (&mut positions, &portals, &belongs).join() {
+            if bel.domain != place { continue; }
+            let Some(((x, y), arrival)) = tiles.next() else { break; };
+            pos.x = x;
+            pos.y = y;
+            arrivals.push((portal.target, arrival));
+        }
+        for (portal, bel) in (&mut portals, &belongs).join() {
+            if portal.target != place { continue; }
+            if let Some((_, arrival)) = arrivals.iter().find(|(from, _)| *from == bel.domain) {
+                portal.warp_place = *arrival;
+            }
+        }
+
+        let mut free_tiles = map.tiles_along_room_edges(&rooms);
+        for (_npc, pos, bel) in (&npcs, &mut positions, &belongs).join() {
+            if bel.domain != place { continue; }
+            if let Some(index) = rng.random_slice_index(&free_tiles) {
+                let (x, y) = free_tiles.remove(index);
+                pos.x = x;
+                pos.y = y;
+            }
+        }
+    }
+}
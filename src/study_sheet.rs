@@ -0,0 +1,56 @@
+use std::fs;
+use std::path::PathBuf;
+use specs::{Join, World, WorldExt};
+use crate::{BelongsTo, Befriendable, Interaction, Name, Place, PlayerName};
+use crate::constants::{STUDY_SHEET_FILE, STUDY_SHEET_ITEMS_STR, STUDY_SHEET_TITLE_STR};
+use crate::i18n::tr;
+use crate::report::{is_lecture, GameStatistics};
+use crate::settings::Settings;
+
+/// Pages of a lecture the player read to the end, all of them once the lecture is completed
+fn pages_heard(interaction: &Interaction, completed: bool) -> &[Vec<String>] {
+    let heard = if completed { interaction.dialogues.len() } else { interaction.dialogue_index.min(interaction.dialogues.len()) };
+    &interaction.dialogues[..heard]
+}
+
+/// Every lecture the player heard, by place and NPC, and the items they earned, as Markdown
+fn study_sheet(ecs: &World) -> String {
+    let statistics = ecs.fetch::<GameStatistics>();
+    let names = ecs.read_storage::<Name>();
+    let interactions = ecs.read_storage::<Interaction>();
+    let belongs = ecs.read_storage::<BelongsTo>();
+    let animals = ecs.read_storage::<Befriendable>();
+
+    let mut contents = format!("# {}: {}\n", tr(STUDY_SHEET_TITLE_STR), ecs.fetch::<PlayerName>().name);
+    for place in Place::all() {
+        let mut lectures = String::new();
+        for (name, interaction, bel, _) in (&names, &interactions, &belongs, !&animals).join() {
+            if bel.domain != place || !is_lecture(interaction) { continue; }
+            let completed = statistics.lectures_completed.contains(&name.name);
+            let pages = pages_heard(interaction, completed);
+            if pages.is_empty() { continue; }
+            lectures.push_str(&format!("\n### {}\n", tr(&name.name)));
+            for page in pages {
+                let lines: Vec<String> = page.iter().map(|line| tr(line)).collect();
+                lectures.push_str(&format!("\n{}\n", lines.join(" ")));
+            }
+        }
+        if !lectures.is_empty() {
+            contents.push_str(&format!("\n## {} ({})\n{}", place.get_name(), place.get_year(), lectures));
+        }
+    }
+    if !statistics.items_earned.is_empty() {
+        contents.push_str(&format!("\n## {}\n\n", tr(STUDY_SHEET_ITEMS_STR)));
+        for item in statistics.items_earned.iter() {
+            contents.push_str(&format!("- {}\n", item));
+        }
+    }
+    contents
+}
+
+/// Writes the study sheet to the export directory of the settings, returns where it went
+pub fn export_study_sheet(ecs: &World) -> std::io::Result<PathBuf> {
+    let path = ecs.fetch::<Settings>().prepare_export(STUDY_SHEET_FILE)?;
+    fs::write(&path, study_sheet(ecs))?;
+    Ok(path)
+}
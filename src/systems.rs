@@ -1,9 +1,22 @@
-use rltk::Point;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use rltk::{Point, RandomNumberGenerator};
 use specs::{Entities, Join, Read, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
-use crate::{BelongsTo, DormantPosition, Item, Map, Name, Npc, Place, Portal, Position, Renderable, RequiresItem, RevealerInformation, RunState, Stored, TargetedPosition, TileType};
-use crate::constants::{ITEM_PORTAL_COLOR, OTTOMAN_COMBINED_REWARD_MOSQUE_MODEL_NAME, OTTOMAN_COMBINED_REWARD_POEM_BOOK_NAME};
-use crate::gamelog::GameLog;
+use crate::{Befriendable, BelongsTo, Companion, DormantPosition, Interaction, Item, Map, Movement, Name, Npc, Objective, Place, Player, PlayerName, PlayerTurn, Portal, Position, Renderable, RequiresItem, RunState, Stored, TargetedPosition, TileType, Trigger};
+use crate::achievements::{Achievement, AchievementToasts, OTTOMAN_LESSON_PLACES};
+use crate::clock::GameClock;
+use crate::constants::{ACHIEVEMENT_UNLOCKED_STR, CLOCK_MINUTES_PER_MOVE, COMPANION_REACTION_COOLDOWN, COMPANION_SNIFF_DISTANCE, CONSOLE_COMPANION_SNIFF, CONSOLE_DIALOGUE_FINISHED, CONSOLE_ITEM_ACQUIRED, CONSOLE_ITEM_CRAFTED, CONSOLE_ITEM_USED, CONSOLE_NEW_OBJECTIVE, HINTS_USED_FLAG, PROFILES_PATH, ITEM_PORTAL_COLOR, NPC_WANDER_CHANCE, OTTOMAN_FROM_SCHOOL_COORD};
+use crate::events::{GameEvent, GameEvents};
+use crate::gamelog::{GameLog, LogCategory};
+use crate::hints::{Hint, Hints};
+use crate::profiles::Profiles;
+use crate::i18n::tr;
 use crate::items::ItemName;
+use crate::learning_records::{record, ObjectKind, Statement, Verb};
+use crate::npcs::MovementBehaviour;
+use crate::palette::EraTransition;
+use crate::report::{is_lecture, GameStatistics};
+use crate::settings::Settings;
+use crate::triggers::{Flags, TriggerCondition, TriggerEffect};
 
 pub struct ItemAdjustmentSystem {}
 
@@ -31,9 +44,8 @@ impl<'a> System<'a> for ItemCollectionSystem {
         ReadExpect<'a, Point>,
         ReadExpect<'a, Place>,
         ReadStorage<'a, Item>,
-        ReadStorage<'a, Name>,
         ReadStorage<'a, BelongsTo>,
-        WriteExpect<'a, GameLog>,
+        WriteExpect<'a, GameEvents>,
         WriteStorage<'a, Position>,
         WriteStorage<'a, Stored>,
         Entities<'a>);
@@ -43,9 +55,8 @@ impl<'a> System<'a> for ItemCollectionSystem {
             player_pos,
             current_place,
             items,
-            names,
             belongs,
-            mut log,
+            mut events,
             mut positions,
             mut stored,
             entities) = data;
@@ -58,82 +69,123 @@ impl<'a> System<'a> for ItemCollectionSystem {
         for item in items_to_store {
             positions.remove(item);
             stored.insert(item, Stored {}).expect("Esya alinamadi");
-            log.entries.push(format!("Esyayi aldin: {}", names.get(item).unwrap().name))
+            events.push(GameEvent::ItemPicked(items.get(item).unwrap().name));
         }
     }
 }
 
-pub struct DoorRevealSystem {}
+pub struct TriggerSystem {}
 
-impl<'a> System<'a> for DoorRevealSystem {
-    type SystemData = (ReadExpect<'a, Point>,
-                       ReadExpect<'a, Place>,
-                       ReadStorage<'a, Stored>,
-                       ReadStorage<'a, Item>,
-                       ReadStorage<'a, RevealerInformation>,
-                       ReadStorage<'a, DormantPosition>,
-                       ReadStorage<'a, BelongsTo>,
+impl<'a> System<'a> for TriggerSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (WriteExpect<'a, Point>,
+                       WriteExpect<'a, Place>,
                        WriteExpect<'a, Map>,
+                       WriteExpect<'a, GameLog>,
+                       WriteExpect<'a, Objective>,
+                       WriteExpect<'a, TargetedPosition>,
+                       WriteExpect<'a, RunState>,
+                       WriteExpect<'a, Flags>,
+                       WriteExpect<'a, GameEvents>,
+                       ReadExpect<'a, GameClock>,
+                       WriteStorage<'a, Trigger>,
+                       WriteStorage<'a, BelongsTo>,
                        WriteStorage<'a, Position>,
-                       Entities<'a>
-    );
+                       ReadStorage<'a, DormantPosition>,
+                       ReadStorage<'a, RequiresItem>,
+                       ReadStorage<'a, Stored>,
+                       ReadStorage<'a, Item>,
+                       ReadStorage<'a, Player>,
+                       Entities<'a>);
 
     fn run(&mut self, data: Self::SystemData) {
         let (
-            player_pos,
-            current_place,
-            stored_items,
-            items,
-            revealer_infos,
-            dormant_positions,
-            belongs_to,
+            mut player_pos,
+            mut current_place,
             mut map,
+            mut log,
+            objective,
+            mut target,
+            mut state,
+            mut flags,
+            mut events,
+            clock,
+            mut triggers,
+            mut belongs_to,
             mut positions,
+            dormant_positions,
+            requires_items,
+            stored_items,
+            items,
+            players,
             entities
         ) = data;
-        for (bel, rev_info, dorm_pos, ent) in (&belongs_to, &revealer_infos, &dormant_positions, &entities).join() {
-            let x_range = rev_info.x_end_points.0..=rev_info.x_end_points.1;
-            let y_range = rev_info.y_end_points.0..=rev_info.y_end_points.1;
-            if bel.domain == *current_place && x_range.contains(&player_pos.x) && y_range.contains(&player_pos.y) {
-                for (item, _stored) in (&items, &stored_items).join() {
-                    if item.name == rev_info.revealer_item && !positions.contains(ent) {
-                        println!("a");
-                        positions.insert(ent, Position { x: dorm_pos.x, y: dorm_pos.y }).expect("Couldn't insert position");
-                        map.tiles[Map::xy_to_tile(dorm_pos.x, dorm_pos.y)] = TileType::RequiresKey;
+        let mut teleport = None;
+        for (trigger, ent) in (&mut triggers, &entities).join() {
+            let domain = belongs_to.get(ent).unwrap().domain;
+            let holds = domain == *current_place && trigger.area.contains(player_pos.x, player_pos.y) && trigger.conditions.iter().all(|condition| match condition {
+                TriggerCondition::HasItem(name) => (&items, &stored_items).join().any(|(item, _)| item.name == *name),
+                TriggerCondition::LacksItem(name) => !(&items, &stored_items).join().any(|(item, _)| item.name == *name),
+                TriggerCondition::FlagSet(flag) => flags.flags.contains(flag),
+                TriggerCondition::FlagUnset(flag) => !flags.flags.contains(flag),
+                TriggerCondition::ObjectiveAt(index) => objective.index == *index,
+                TriggerCondition::TimeBetween { opening_hour, closing_hour } => clock.is_between(*opening_hour, *closing_hour),
+            });
+            let effects = if holds && !trigger.active {
+                trigger.active = true;
+                if trigger.fired && !trigger.repeatable { continue; }
+                trigger.fired = true;
+                trigger.effects.clone()
+            } else if !holds && trigger.active {
+                trigger.active = false;
+                trigger.exit_effects.clone()
+            } else {
+                continue;
+            };
+            for effect in effects {
+                match effect {
+                    TriggerEffect::RevealDormant => {
+                        if let Some(dormant) = dormant_positions.get(ent) {
+                            positions.insert(ent, Position { x: dormant.x, y: dormant.y }).expect("Couldn't insert position");
+                            map.tiles[Map::xy_to_tile(dormant.x, dormant.y)] = match requires_items.contains(ent) {
+                                true => TileType::RequiresKey,
+                                false => TileType::Portal
+                            };
+                        }
+                    }
+                    TriggerEffect::HideDormant { before_reveal } => {
+                        positions.remove(ent);
+                        if let Some(dormant) = dormant_positions.get(ent) {
+                            if map.place == domain {
+                                map.tiles[Map::xy_to_tile(dormant.x, dormant.y)] = before_reveal;
+                            }
+                        }
+                    }
+                    TriggerEffect::ChangeTile { x, y, tile } => {
+                        if map.place == domain {
+                            map.tiles[Map::xy_to_tile(x, y)] = tile;
+                        }
+                    }
+                    TriggerEffect::Log(line) => log.push(LogCategory::Quest, &clock, tr(&line)),
+                    TriggerEffect::StartDialogue { x, y } => {
+                        target.x = x;
+                        target.y = y;
+                        *state = RunState::InteractNpc { index: 0 };
+                    }
+                    TriggerEffect::AdvanceObjective => events.push(GameEvent::ObjectiveAdvanced),
+                    TriggerEffect::Teleport { place, x, y } => teleport = Some((place, x, y)),
+                    TriggerEffect::SetFlag(flag) => {
+                        flags.flags.insert(flag);
                     }
                 }
-            } else if positions.contains(ent) {
-                positions.remove(ent);
-                map.tiles[Map::xy_to_tile(dorm_pos.x, dorm_pos.y)] = revealer_infos.get(ent).unwrap().before_reveal;
             }
         }
-    }
-}
-
-pub struct GameStateAdjustmentSystem {}
-
-impl<'a> System<'a> for GameStateAdjustmentSystem {
-    type SystemData = (ReadExpect<'a, Place>,
-                       ReadExpect<'a, TargetedPosition>,
-                       ReadStorage<'a, Npc>,
-                       ReadStorage<'a, BelongsTo>,
-                       ReadStorage<'a, Position>,
-                       WriteExpect<'a, RunState>);
-
-    fn run(&mut self, data: Self::SystemData) {
-        let (
-            current_place,
-            target_pos,
-            npcs,
-            belongs_to,
-            positions,
-            mut state
-        ) = data;
-        if target_pos.x >= 0 && target_pos.y >= 0 {
-            for (_npcs, belongs, pos) in (&npcs, &belongs_to, &positions).join() {
-                if pos.x == target_pos.x && pos.y == target_pos.y && belongs.domain == *current_place {
-                    *state = RunState::InteractNpc { index: 0 };
-                }
+        if let Some((place, x, y)) = teleport {
+            *current_place = place;
+            *player_pos = Point::new(x, y);
+            events.push(GameEvent::PlaceEntered(place));
+            for (bel, _player) in (&mut belongs_to, &players).join() {
+                bel.domain = place;
             }
         }
     }
@@ -145,14 +197,14 @@ impl<'a> System<'a> for ItemCombinationSystem {
     type SystemData = (WriteStorage<'a, Stored>,
                        ReadStorage<'a, Item>,
                        Entities<'a>,
-                       WriteExpect<'a, GameLog>);
+                       WriteExpect<'a, GameEvents>);
 
     fn run(&mut self, data: Self::SystemData) {
         let (
             mut stored_items,
             items,
             entities,
-            mut log
+            mut events
         ) = data;
 
         let mut contains_poem_piece = false;
@@ -187,7 +239,7 @@ impl<'a> System<'a> for ItemCombinationSystem {
             for (item, ent) in (&items, &entities).join() {
                 if item.name == ItemName::OttomanCombinedRewardPoemBook {
                     stored_items.insert(ent, Stored {}).expect("Couldn't insert sorted");
-                    log.entries.push(format!("Yeni Esya Urettin: {}", OTTOMAN_COMBINED_REWARD_POEM_BOOK_NAME))
+                    events.push(GameEvent::ItemCrafted(item.name))
                 }
             }
             contains_book_cover = false;
@@ -204,7 +256,7 @@ impl<'a> System<'a> for ItemCombinationSystem {
             for (item, ent) in (&items, &entities).join() {
                 if item.name == ItemName::OttomanCombinedRewardMosqueModel {
                     stored_items.insert(ent, Stored {}).expect("Couldn't insert sorted");
-                    log.entries.push(format!("Yeni Esya Urettin: {}", OTTOMAN_COMBINED_REWARD_MOSQUE_MODEL_NAME))
+                    events.push(GameEvent::ItemCrafted(item.name))
                 }
             }
             contains_mosque_1 = false;
@@ -220,7 +272,7 @@ impl<'a> System<'a> for ItemCombinationSystem {
             for (item, ent) in (&items, &entities).join() {
                 if item.name == ItemName::OttomanCombinedRewardWeirdCollage {
                     stored_items.insert(ent, Stored {}).expect("Couldn't insert sorted");
-                    log.entries.push(format!("Yeni Esya Urettin: {}", OTTOMAN_COMBINED_REWARD_MOSQUE_MODEL_NAME))
+                    events.push(GameEvent::ItemCrafted(item.name))
                 }
             }
             contains_note_paper = false;
@@ -228,4 +280,446 @@ impl<'a> System<'a> for ItemCombinationSystem {
             contains_clay = false;
         }
     }
-}
\ No newline at end of file
+}
+
+pub struct NpcMovementSystem {}
+
+impl<'a> System<'a> for NpcMovementSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (ReadExpect<'a, Point>,
+                       ReadExpect<'a, Place>,
+                       ReadExpect<'a, PlayerTurn>,
+                       WriteExpect<'a, RandomNumberGenerator>,
+                       WriteExpect<'a, Map>,
+                       ReadStorage<'a, BelongsTo>,
+                       ReadStorage<'a, DormantPosition>,
+                       ReadStorage<'a, Item>,
+                       WriteStorage<'a, Movement>,
+                       WriteStorage<'a, Position>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            player_pos,
+            current_place,
+            turn,
+            mut rng,
+            mut map,
+            belongs_to,
+            dormant_positions,
+            items,
+            mut movements,
+            mut positions
+        ) = data;
+        if !turn.acted { return; }
+        // Stepping off a dormant door would overwrite it with floor, revealed or not
+        let doors: Vec<(i32, i32)> = (&dormant_positions, &belongs_to).join()
+            .filter(|(_, bel)| bel.domain == *current_place)
+            .map(|(dormant, _)| (dormant.x, dormant.y))
+            .collect();
+        // An NPC standing on an item would keep the player from picking it up
+        let item_tiles: Vec<(i32, i32)> = (&items, &positions, &belongs_to).join()
+            .filter(|(_, _, bel)| bel.domain == *current_place)
+            .map(|(_, pos, _)| (pos.x, pos.y))
+            .collect();
+        for (bel, movement, pos) in (&belongs_to, &mut movements, &mut positions).join() {
+            if bel.domain != *current_place { continue; }
+            let (new_x, new_y) = match &mut movement.behaviour {
+                MovementBehaviour::Wander => {
+                    if rng.range(0, NPC_WANDER_CHANCE) != 0 { continue; }
+                    let (delta_x, delta_y) = [(-1, 0), (1, 0), (0, -1), (0, 1)][rng.range(0usize, 4)];
+                    (pos.x + delta_x, pos.y + delta_y)
+                }
+                MovementBehaviour::Patrol { route, index } => {
+                    if (pos.x, pos.y) == route[*index] {
+                        *index = (*index + 1) % route.len();
+                    }
+                    let (target_x, target_y) = route[*index];
+                    let path = rltk::a_star_search(Map::xy_to_tile(pos.x, pos.y), Map::xy_to_tile(target_x, target_y), &*map);
+                    if !path.success || path.steps.len() < 2 { continue; }
+                    (path.steps[1] as i32 % map.width, path.steps[1] as i32 / map.width)
+                }
+            };
+            if new_x < 0 || new_x >= map.width || new_y < 0 || new_y >= map.height { continue; }
+            if map.tiles[Map::xy_to_tile(new_x, new_y)] != TileType::Floor || (new_x == player_pos.x && new_y == player_pos.y) { continue; }
+            if doors.contains(&(new_x, new_y)) || item_tiles.contains(&(new_x, new_y)) { continue; }
+            map.tiles[Map::xy_to_tile(pos.x, pos.y)] = TileType::Floor;
+            map.tiles[Map::xy_to_tile(new_x, new_y)] = TileType::NPC;
+            pos.x = new_x;
+            pos.y = new_y;
+        }
+    }
+}
+
+/// Advances the clock when the player spent a turn
+pub struct ClockSystem {}
+
+impl<'a> System<'a> for ClockSystem {
+    type SystemData = (ReadExpect<'a, PlayerTurn>,
+                       WriteExpect<'a, GameClock>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (turn, mut clock) = data;
+        if turn.acted {
+            clock.advance(CLOCK_MINUTES_PER_MOVE);
+        }
+    }
+}
+
+/// Closes the player's turn, NPCs wait for the next one
+pub struct TurnEndSystem {}
+
+impl<'a> System<'a> for TurnEndSystem {
+    type SystemData = (WriteExpect<'a, PlayerTurn>,
+                       WriteExpect<'a, Hints>,
+                       ReadExpect<'a, Settings>,
+                       WriteExpect<'a, Flags>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (mut turn, mut hints, settings, mut flags) = data;
+        if turn.acted {
+            hints.pass_turn();
+            if settings.hints && !flags.flags.contains(HINTS_USED_FLAG) {
+                flags.flags.insert(HINTS_USED_FLAG.to_string());
+            }
+        }
+        turn.acted = false;
+    }
+}
+
+pub struct CompanionSystem {}
+
+impl<'a> System<'a> for CompanionSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (ReadExpect<'a, Point>,
+                       ReadExpect<'a, Place>,
+                       ReadExpect<'a, PlayerTurn>,
+                       WriteExpect<'a, Map>,
+                       WriteExpect<'a, GameLog>,
+                       ReadExpect<'a, GameClock>,
+                       ReadStorage<'a, Name>,
+                       ReadStorage<'a, DormantPosition>,
+                       WriteStorage<'a, Companion>,
+                       WriteStorage<'a, BelongsTo>,
+                       WriteStorage<'a, Position>,
+                       Entities<'a>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            player_pos,
+            current_place,
+            turn,
+            mut map,
+            mut log,
+            clock,
+            names,
+            dormant_positions,
+            mut companions,
+            mut belongs_to,
+            mut positions,
+            entities
+        ) = data;
+        // Stepping off a dormant door would overwrite it with floor, revealed or not
+        let doors: Vec<(i32, i32)> = (&dormant_positions, &belongs_to).join()
+            .filter(|(_, bel)| bel.domain == *current_place)
+            .map(|(dormant, _)| (dormant.x, dormant.y))
+            .collect();
+        for (companion, name, ent) in (&mut companions, &names, &entities).join() {
+            let domain = belongs_to.get(ent).unwrap().domain;
+            if domain != *current_place {
+                // The player went through a portal, so follow them to the closest free tile
+                let free_tile = [(0, 1), (0, -1), (1, 0), (-1, 0), (1, 1), (-1, 1), (1, -1), (-1, -1)].iter()
+                    .map(|(delta_x, delta_y)| (player_pos.x + delta_x, player_pos.y + delta_y))
+                    .find(|(x, y)| map.tiles[Map::xy_to_tile(*x, *y)] == TileType::Floor && !doors.contains(&(*x, *y)));
+                if let Some((x, y)) = free_tile {
+                    belongs_to.get_mut(ent).unwrap().domain = *current_place;
+                    positions.insert(ent, Position { x, y }).expect("Couldn't insert position");
+                    map.tiles[Map::xy_to_tile(x, y)] = TileType::NPC;
+                }
+                continue;
+            }
+            if !turn.acted { continue; }
+            let Some(pos) = positions.get(ent).cloned() else { continue; };
+            if (pos.x - player_pos.x).abs() + (pos.y - player_pos.y).abs() > 1 {
+                let path = rltk::a_star_search(Map::xy_to_tile(pos.x, pos.y), Map::xy_to_tile(player_pos.x, player_pos.y), &*map);
+                if path.success && path.steps.len() > 2 {
+                    let (new_x, new_y) = (path.steps[1] as i32 % map.width, path.steps[1] as i32 / map.width);
+                    if !doors.contains(&(new_x, new_y)) {
+                        map.tiles[Map::xy_to_tile(pos.x, pos.y)] = TileType::Floor;
+                        map.tiles[Map::xy_to_tile(new_x, new_y)] = TileType::NPC;
+                        let pos = positions.get_mut(ent).unwrap();
+                        pos.x = new_x;
+                        pos.y = new_y;
+                    }
+                }
+            }
+
+            if companion.reaction_cooldown > 0 {
+                companion.reaction_cooldown -= 1;
+                continue;
+            }
+            let mut sniffed = false;
+            for (dormant, bel, dormant_ent) in (&dormant_positions, &belongs_to, &entities).join() {
+                let hidden = !positions.contains(dormant_ent);
+                let distance = (dormant.x - pos.x).abs().max((dormant.y - pos.y).abs());
+                if hidden && bel.domain == *current_place && distance <= COMPANION_SNIFF_DISTANCE {
+                    sniffed = true;
+                }
+            }
+            if sniffed {
+                log.push(LogCategory::Dialogue, &clock, format!("{} {}", tr(&name.name), tr(CONSOLE_COMPANION_SNIFF)));
+                companion.reaction_cooldown = COMPANION_REACTION_COOLDOWN;
+            }
+        }
+    }
+}
+
+/// Writes the events of the tick to the game log
+pub struct EventLogSystem {}
+
+impl<'a> System<'a> for EventLogSystem {
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       ReadExpect<'a, GameClock>,
+                       WriteExpect<'a, GameLog>,
+                       ReadStorage<'a, Name>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, clock, mut log, names) = data;
+        for event in events.events.iter() {
+            match event {
+                GameEvent::ItemPicked(name) => log.push(LogCategory::Item, &clock, format!("{} {}", tr(CONSOLE_ITEM_ACQUIRED), name)),
+                GameEvent::ItemUsed(name) => log.push(LogCategory::Item, &clock, format!("{} {}", tr(CONSOLE_ITEM_USED), name)),
+                GameEvent::ItemCrafted(name) => log.push(LogCategory::Item, &clock, format!("{} {}", tr(CONSOLE_ITEM_CRAFTED), name)),
+                GameEvent::DialogueFinished(npc) => {
+                    if let Some(name) = names.get(*npc) {
+                        log.push(LogCategory::Dialogue, &clock, format!("{} {}", tr(&name.name), tr(CONSOLE_DIALOGUE_FINISHED)));
+                    }
+                }
+                GameEvent::PlaceEntered(_) | GameEvent::DialogueStarted(_) | GameEvent::DoorUnlocked(_) | GameEvent::ObjectiveAdvanced => {}
+            }
+        }
+    }
+}
+
+/// Moves on to the next objective when a dialogue asks for it
+pub struct ObjectiveSystem {}
+
+impl<'a> System<'a> for ObjectiveSystem {
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       WriteExpect<'a, Objective>,
+                       ReadExpect<'a, GameClock>,
+                       WriteExpect<'a, GameLog>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, mut objective, clock, mut log) = data;
+        for event in events.events.iter() {
+            if matches!(event, GameEvent::ObjectiveAdvanced) && objective.index + 1 < objective.objectives.len() {
+                objective.index += 1;
+                log.push(LogCategory::Quest, &clock, format!("{} {}", tr(CONSOLE_NEW_OBJECTIVE), tr(&objective.objectives[objective.index])));
+            }
+        }
+    }
+}
+
+/// Starts the palette transition when the player steps into another era
+pub struct EraTransitionSystem {}
+
+impl<'a> System<'a> for EraTransitionSystem {
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       WriteExpect<'a, EraTransition>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, mut transition) = data;
+        for event in events.events.iter() {
+            if let GameEvent::PlaceEntered(place) = event {
+                transition.enter(place.get_era());
+            }
+        }
+    }
+}
+
+/// Explains crafting the first time an item is crafted
+pub struct HintSystem {}
+
+impl<'a> System<'a> for HintSystem {
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       WriteExpect<'a, Hints>,
+                       WriteExpect<'a, Profiles>,
+                       ReadExpect<'a, Settings>,
+                       WriteExpect<'a, GameLog>,
+                       ReadExpect<'a, GameClock>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, mut hints, mut profiles, settings, mut log, clock) = data;
+        let crafted = events.events.iter().any(|event| matches!(event, GameEvent::ItemCrafted(_)));
+        if settings.hints && crafted && hints.show(&mut profiles, Hint::ItemCrafted).is_err() {
+            log.push_write_failure(&clock, PROFILES_PATH);
+        }
+    }
+}
+
+/// Adds the lectures the player hears to the codex of the active profile
+pub struct CodexSystem {}
+
+impl<'a> System<'a> for CodexSystem {
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       WriteExpect<'a, Profiles>,
+                       WriteExpect<'a, GameLog>,
+                       ReadExpect<'a, GameClock>,
+                       ReadStorage<'a, Name>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, mut profiles, mut log, clock, names) = data;
+        let Some(profile) = profiles.active_mut() else { return; };
+        let mut learned = false;
+        for event in events.events.iter() {
+            if let GameEvent::DialogueFinished(npc) = event {
+                if let Some(name) = names.get(*npc) {
+                    learned |= profile.codex.insert(name.name.clone());
+                }
+            }
+        }
+        if learned && profiles.save().is_err() {
+            log.push_write_failure(&clock, PROFILES_PATH);
+        }
+    }
+}
+
+/// Unlocks the achievements of the active profile as the player reaches their milestones
+pub struct AchievementSystem {}
+
+impl<'a> System<'a> for AchievementSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       ReadExpect<'a, Point>,
+                       ReadExpect<'a, Flags>,
+                       ReadExpect<'a, GameClock>,
+                       WriteExpect<'a, Profiles>,
+                       WriteExpect<'a, AchievementToasts>,
+                       WriteExpect<'a, GameLog>,
+                       ReadStorage<'a, Name>,
+                       ReadStorage<'a, Npc>,
+                       ReadStorage<'a, Befriendable>,
+                       ReadStorage<'a, BelongsTo>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, player_pos, flags, clock, mut profiles, mut toasts, mut log, names, npcs, befriendables, belongs_to) = data;
+        let Some(profile) = profiles.active_mut() else { return; };
+        let mut reached = Vec::new();
+        for event in events.events.iter() {
+            match event {
+                GameEvent::DialogueFinished(_) => {
+                    // The codex system has already added the lecture of the event
+                    if (&names, &befriendables).join().all(|(name, _)| profile.codex.contains(&name.name)) {
+                        reached.push(Achievement::CampusAnimals);
+                    }
+                    let lessons_finished = (&names, &npcs, &belongs_to).join()
+                        .filter(|(_, _, bel)| OTTOMAN_LESSON_PLACES.contains(&bel.domain))
+                        .all(|(name, _, _)| profile.codex.contains(&name.name));
+                    if lessons_finished {
+                        reached.push(Achievement::OttomanLessons);
+                    }
+                }
+                GameEvent::PlaceEntered(Place::OTTOMAN_MAIN) => {
+                    let through_secret_gate = (player_pos.x, player_pos.y) == OTTOMAN_FROM_SCHOOL_COORD;
+                    if through_secret_gate && !flags.flags.contains(HINTS_USED_FLAG) {
+                        reached.push(Achievement::SecretGate);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut unlocked = false;
+        for achievement in reached {
+            if profile.achievements.insert(achievement.id()) {
+                unlocked = true;
+                toasts.push(achievement);
+                log.push(LogCategory::Quest, &clock, format!("{} {}", tr(ACHIEVEMENT_UNLOCKED_STR), achievement.get_name()));
+            }
+        }
+        if unlocked && profiles.save().is_err() {
+            log.push_write_failure(&clock, PROFILES_PATH);
+        }
+    }
+}
+
+/// Counts the places, people, lectures and items of the game for its report card and study sheet
+pub struct StatisticsSystem {}
+
+impl<'a> System<'a> for StatisticsSystem {
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       WriteExpect<'a, GameStatistics>,
+                       ReadStorage<'a, Name>,
+                       ReadStorage<'a, Interaction>,
+                       ReadStorage<'a, Befriendable>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, mut statistics, names, interactions, animals) = data;
+        for event in events.events.iter() {
+            match event {
+                GameEvent::PlaceEntered(place) => statistics.visit(*place),
+                GameEvent::ItemPicked(item) | GameEvent::ItemCrafted(item) => statistics.earn(*item),
+                GameEvent::DialogueStarted(npc) => {
+                    if let Some(name) = names.get(*npc) {
+                        statistics.speak_to(&name.name);
+                    }
+                }
+                GameEvent::DialogueFinished(npc) => {
+                    let lecture = !animals.contains(*npc) && interactions.get(*npc).is_some_and(is_lecture);
+                    if let (true, Some(name)) = (lecture, names.get(*npc)) {
+                        statistics.complete_lecture(&name.name);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Writes learning records of the places the player enters and the lectures they hear
+pub struct LearningRecordSystem {
+    /// The posting threads send back the endpoints they could not reach
+    failed_posts: (Sender<String>, Receiver<String>),
+}
+
+impl Default for LearningRecordSystem {
+    fn default() -> Self {
+        LearningRecordSystem { failed_posts: channel() }
+    }
+}
+
+impl<'a> System<'a> for LearningRecordSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (ReadExpect<'a, GameEvents>,
+                       ReadExpect<'a, Settings>,
+                       ReadExpect<'a, PlayerName>,
+                       ReadExpect<'a, GameClock>,
+                       WriteExpect<'a, GameLog>,
+                       ReadStorage<'a, Name>,
+                       ReadStorage<'a, Interaction>,
+                       ReadStorage<'a, Befriendable>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (events, settings, player, clock, mut log, names, interactions, animals) = data;
+        for endpoint in self.failed_posts.1.try_iter() {
+            log.push_write_failure(&clock, &endpoint);
+        }
+        if !settings.learning_records { return; }
+        for event in events.events.iter() {
+            let statement = match event {
+                GameEvent::PlaceEntered(place) => {
+                    Statement::new(&player.name, Verb::Experienced, ObjectKind::Place, place.definition().id, place.get_name())
+                }
+                GameEvent::DialogueStarted(npc) | GameEvent::DialogueFinished(npc) => {
+                    let lecture = !animals.contains(*npc) && interactions.get(*npc).is_some_and(is_lecture);
+                    let Some(name) = names.get(*npc).filter(|_| lecture) else { continue; };
+                    let verb = if matches!(event, GameEvent::DialogueStarted(_)) { Verb::Experienced } else { Verb::Completed };
+                    Statement::new(&player.name, verb, ObjectKind::Lecture, &name.name, tr(&name.name))
+                }
+                _ => continue,
+            };
+            if record(&settings, &statement, &self.failed_posts.0).is_err() {
+                log.push_write_failure(&clock, &settings.learning_records_file);
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use crate::items::ItemName;
+use crate::{Place, TileType};
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub enum TriggerArea {
+    Tile { x: i32, y: i32 },
+    /// Inclusive end points on both axes
+    Area { x_end_points: (i32, i32), y_end_points: (i32, i32) },
+}
+
+impl TriggerArea {
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        match self {
+            TriggerArea::Tile { x: tile_x, y: tile_y } => x == *tile_x && y == *tile_y,
+            TriggerArea::Area { x_end_points, y_end_points } => {
+                (x_end_points.0..=x_end_points.1).contains(&x) && (y_end_points.0..=y_end_points.1).contains(&y)
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub enum TriggerCondition {
+    HasItem(ItemName),
+    LacksItem(ItemName),
+    FlagSet(String),
+    FlagUnset(String),
+    /// The objective index, which is how far the quest has come
+    ObjectiveAt(usize),
+    /// The clock's hour is in [opening_hour, closing_hour), e.g. opening hours of a building
+    TimeBetween { opening_hour: u64, closing_hour: u64 },
+}
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub enum TriggerEffect {
+    /// Puts the trigger's own entity on its dormant position
+    RevealDormant,
+    /// Takes the trigger's own entity off the map, leaving the given tile behind
+    HideDormant { before_reveal: TileType },
+    ChangeTile { x: i32, y: i32, tile: TileType },
+    /// Key into the string tables, translated when the line is logged
+    Log(String),
+    /// Starts talking to the NPC on the given tile
+    StartDialogue { x: i32, y: i32 },
+    AdvanceObjective,
+    Teleport { place: Place, x: i32, y: i32 },
+    SetFlag(String),
+}
+
+/// Named story flags that triggers can set and check
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Flags {
+    pub flags: HashSet<String>,
+}